@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::Path;
+use thiserror::Error as ThisError;
+use toml::value::{Table, Value};
+
+use crate::solver::Part;
+
+/// Known puzzle answers, stored as one `[dayN]` table with `a` and `b` keys per day
+#[derive(Debug, Default)]
+pub struct Answers(Table);
+
+#[derive(ThisError, Debug)]
+pub enum AnswersError {
+    #[error("Failed to read answers file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse answers file: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("Expected a table for {0}")]
+    InvalidDay(String),
+    #[error("Expected an integer or string answer for {0}")]
+    InvalidAnswer(String),
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(input: &str) -> Result<Self, AnswersError> {
+        let table = toml::from_str::<Table>(input)?;
+
+        for (day, parts) in table.iter() {
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| AnswersError::InvalidDay(day.clone()))?;
+
+            for (part, answer) in parts.iter() {
+                if !matches!(answer, Value::Integer(_) | Value::String(_)) {
+                    return Err(AnswersError::InvalidAnswer(format!("{}{}", day, part)));
+                }
+            }
+        }
+
+        Ok(Self(table))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        self.get_named(&format!("day{}", day), part)
+    }
+
+    /// Names of all answer tables
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Looks up the answer stored in an arbitrarily named table
+    pub fn get_named(&self, name: &str, part: Part) -> Option<String> {
+        let answer = self.0.get(name)?.get(part.to_string())?;
+
+        match answer {
+            Value::Integer(v) => Some(v.to_string()),
+            Value::String(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, AnswersError};
+    use crate::solver::Part;
+
+    #[test]
+    fn test_get_answers() {
+        let answers = Answers::parse(
+            r#"
+                [day1]
+                a = 444019
+                b = "29212176"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Some(String::from("444019")), answers.get(1, Part::A));
+        assert_eq!(Some(String::from("29212176")), answers.get(1, Part::B));
+        assert_eq!(None, answers.get(2, Part::A));
+    }
+
+    #[test]
+    fn test_invalid_answer() {
+        let result = Answers::parse(
+            r#"
+                [day1]
+                a = 1.5
+            "#,
+        );
+
+        assert!(matches!(result, Err(AnswersError::InvalidAnswer(_))));
+    }
+}
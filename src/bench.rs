@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+use crate::solver::{Result, Solution};
+
+/// Durations of a single parse/part1/part2 run
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseTimings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+pub struct Benchmark {
+    pub day: u8,
+    run: fn(&str) -> Result<PhaseTimings>,
+}
+
+impl Benchmark {
+    pub const fn new(day: u8, run: fn(&str) -> Result<PhaseTimings>) -> Self {
+        Self { day, run }
+    }
+
+    pub fn run(&self, input: &str, iterations: NonZeroUsize) -> Result<DayReport> {
+        let mut runs = Vec::with_capacity(iterations.get());
+        for _ in 0..iterations.get() {
+            runs.push((self.run)(input)?);
+        }
+
+        Ok(DayReport {
+            day: self.day,
+            parse: Stats::new(runs.iter().map(|t| t.parse).collect()),
+            part1: Stats::new(runs.iter().map(|t| t.part1).collect()),
+            part2: Stats::new(runs.iter().map(|t| t.part2).collect()),
+        })
+    }
+}
+
+pub fn time_phases<S: Solution>(input: &str) -> Result<PhaseTimings> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    S::part1(&parsed)?;
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    S::part2(&parsed)?;
+    let part2 = start.elapsed();
+
+    Ok(PhaseTimings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Min/median/max of a set of durations in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn new(mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+
+        let nanos = |d: &Duration| d.as_nanos() as u64;
+
+        Self {
+            min_ns: durations.first().map(nanos).unwrap_or_default(),
+            median_ns: durations
+                .get(durations.len() / 2)
+                .map(nanos)
+                .unwrap_or_default(),
+            max_ns: durations.last().map(nanos).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn day(&self, day: u8) -> Option<&DayReport> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(vec![
+            Duration::from_nanos(30),
+            Duration::from_nanos(10),
+            Duration::from_nanos(50),
+            Duration::from_nanos(20),
+            Duration::from_nanos(40),
+        ]);
+
+        assert_eq!(
+            Stats {
+                min_ns: 10,
+                median_ns: 30,
+                max_ns: 50
+            },
+            stats
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::parse::{self, ParseResult};
+use crate::solver::{Details, Result, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Self::Output1> {
+        Ok(find_combination(numbers, 2)?.product())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Self::Output2> {
+        Ok(find_combination(numbers, 3)?.product())
+    }
+
+    fn details1(numbers: &Self::Input) -> Result<Details> {
+        combination_details(numbers, 2)
+    }
+
+    fn details2(numbers: &Self::Input) -> Result<Details> {
+        combination_details(numbers, 3)
+    }
+}
+
+fn find_combination(numbers: &[u64], k: usize) -> Result<Combination> {
+    Ok(k_sum(numbers, k, 2020).ok_or("Couldn't find result")?)
+}
+
+fn combination_details(numbers: &[u64], k: usize) -> Result<Details> {
+    let mut details = find_combination(numbers, k)?.details();
+    details.insert(
+        String::from("combinations"),
+        k_sum_count(numbers, k, 2020, Duplicates::ByIndex).into(),
+    );
+    details.insert(
+        String::from("distinct_combinations"),
+        k_sum_count(numbers, k, 2020, Duplicates::ByValue).into(),
+    );
+
+    Ok(details)
+}
+
+/// Numbers picked from distinct positions of the input, ordered by their index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub values: Vec<u64>,
+}
+
+impl Combination {
+    fn new(numbers: &[u64], mut indices: Vec<usize>) -> Self {
+        indices.sort_unstable();
+        let values = indices.iter().map(|idx| numbers[*idx]).collect();
+
+        Self { indices, values }
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.values.iter().sum()
+    }
+
+    pub fn product(&self) -> u64 {
+        self.values.iter().product()
+    }
+
+    pub fn details(&self) -> Details {
+        let mut details = Details::new();
+        details.insert(String::from("indices"), self.indices.clone().into());
+        details.insert(String::from("values"), self.values.clone().into());
+
+        details
+    }
+}
+
+/// Finds `k` numbers at distinct indices that sum up to `target`
+///
+/// Pairs are found by hashing in O(n), triples by sorting and a two-pointer scan in O(n²)
+/// and larger combinations by meeting in the middle of two halves of size k/2.
+pub fn k_sum(numbers: &[u64], k: usize, target: u64) -> Option<Combination> {
+    let indices = match k {
+        _ if k > numbers.len() => None,
+        0 if target == 0 => Some(Vec::new()),
+        0 => None,
+        1 => numbers
+            .iter()
+            .position(|n| *n == target)
+            .map(|idx| vec![idx]),
+        2 => two_sum(numbers, target),
+        3 => three_sum(numbers, target),
+        _ => meet_in_the_middle(numbers, k, target),
+    }?;
+
+    Some(Combination::new(numbers, indices))
+}
+
+fn two_sum(numbers: &[u64], target: u64) -> Option<Vec<usize>> {
+    let mut seen = HashMap::new();
+
+    for (idx, n) in numbers.iter().enumerate() {
+        if let Some(other) = target.checked_sub(*n).and_then(|rest| seen.get(&rest)) {
+            return Some(vec![*other, idx]);
+        }
+
+        seen.entry(*n).or_insert(idx);
+    }
+
+    None
+}
+
+fn three_sum(numbers: &[u64], target: u64) -> Option<Vec<usize>> {
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    order.sort_unstable_by_key(|idx| numbers[*idx]);
+    let value = |pos: usize| numbers[order[pos]] as u128;
+
+    for first in 0..order.len() {
+        let rest = match (target as u128).checked_sub(value(first)) {
+            Some(rest) => rest,
+            None => break,
+        };
+
+        let (mut lo, mut hi) = (first + 1, order.len() - 1);
+        while lo < hi {
+            let sum = value(lo) + value(hi);
+
+            if sum == rest {
+                return Some(vec![order[first], order[lo], order[hi]]);
+            } else if sum < rest {
+                lo += 1;
+            } else {
+                hi -= 1;
+            }
+        }
+    }
+
+    None
+}
+
+fn meet_in_the_middle(numbers: &[u64], k: usize, target: u64) -> Option<Vec<usize>> {
+    let sum = |indices: &[usize]| -> Option<u64> {
+        indices
+            .iter()
+            .try_fold(0u64, |sum, idx| sum.checked_add(numbers[*idx]))
+            .filter(|sum| *sum <= target)
+    };
+
+    let mut halves: HashMap<u64, Vec<Vec<usize>>> = HashMap::new();
+    for indices in (0..numbers.len()).combinations(k / 2) {
+        if let Some(sum) = sum(&indices) {
+            halves.entry(sum).or_default().push(indices);
+        }
+    }
+
+    (0..numbers.len())
+        .combinations(k - k / 2)
+        .find_map(|indices| {
+            let half = halves.get(&(target - sum(&indices)?))?;
+
+            half.iter()
+                .find(|other| other.iter().all(|idx| !indices.contains(idx)))
+                .map(|other| indices.iter().chain(other).copied().collect())
+        })
+}
+
+/// How combinations consisting of equal values at different indices are told apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplicates {
+    /// Every set of indices is a combination of its own
+    ByIndex,
+    /// Combinations with equal values are reported once, using the lowest indices
+    ByValue,
+}
+
+/// Lazily enumerates all combinations of `k` numbers at distinct indices that sum up
+/// to `target`, smaller values are picked first
+pub fn k_sum_iter(numbers: &[u64], k: usize, target: u64, duplicates: Duplicates) -> KSums<'_> {
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    order.sort_by_key(|idx| numbers[*idx]);
+
+    KSums {
+        numbers,
+        order,
+        k,
+        target: target as u128,
+        duplicates,
+        stack: Vec::with_capacity(k),
+        sum: 0,
+        next: 0,
+        done: false,
+    }
+}
+
+pub fn k_sum_all(
+    numbers: &[u64],
+    k: usize,
+    target: u64,
+    duplicates: Duplicates,
+) -> Vec<Combination> {
+    k_sum_iter(numbers, k, target, duplicates).collect()
+}
+
+/// Counts the combinations found by [`k_sum_iter`] without enumerating them
+pub fn k_sum_count(numbers: &[u64], k: usize, target: u64, duplicates: Duplicates) -> u64 {
+    // (values with the multiplicity they may be picked with)
+    let values = match duplicates {
+        Duplicates::ByIndex => numbers.iter().map(|n| (*n, 1)).collect::<Vec<_>>(),
+        Duplicates::ByValue => {
+            let mut values: Vec<(u64, usize)> = Vec::new();
+            for n in numbers.iter().sorted() {
+                match values.last_mut() {
+                    Some((value, count)) if value == n => *count += 1,
+                    _ => values.push((*n, 1)),
+                }
+            }
+
+            values
+        }
+    };
+
+    // counts[j][sum] is the number of ways to pick j values adding up to sum
+    let mut counts: Vec<HashMap<u64, u64>> = vec![HashMap::new(); k + 1];
+    counts[0].insert(0, 1);
+
+    for (value, multiplicity) in values {
+        for picked in (0..k).rev() {
+            let partial_sums = counts[picked]
+                .iter()
+                .map(|(sum, count)| (*sum, *count))
+                .collect::<Vec<_>>();
+
+            for (sum, count) in partial_sums {
+                let mut sum = sum;
+
+                for times in 1..=multiplicity.min(k - picked) {
+                    sum = match sum.checked_add(value).filter(|sum| *sum <= target) {
+                        Some(sum) => sum,
+                        None => break,
+                    };
+
+                    *counts[picked + times].entry(sum).or_default() += count;
+                }
+            }
+        }
+    }
+
+    counts[k].get(&target).copied().unwrap_or_default()
+}
+
+/// Iterator returned by [`k_sum_iter`], a depth first search over the numbers in ascending
+/// order that abandons a branch as soon as its smallest possible sum exceeds the target
+pub struct KSums<'a> {
+    numbers: &'a [u64],
+    order: Vec<usize>,
+    k: usize,
+    target: u128,
+    duplicates: Duplicates,
+    /// Positions within `order` of the currently picked numbers
+    stack: Vec<usize>,
+    sum: u128,
+    /// Position within `order` of the next number to try
+    next: usize,
+    done: bool,
+}
+
+impl KSums<'_> {
+    fn value(&self, pos: usize) -> u128 {
+        self.numbers[self.order[pos]] as u128
+    }
+
+    /// Removes the last picked number and moves on to the next candidate in its place
+    fn backtrack(&mut self) {
+        let pos = match self.stack.pop() {
+            Some(pos) => pos,
+            None => {
+                self.done = true;
+                return;
+            }
+        };
+
+        self.sum -= self.value(pos);
+        self.next = pos + 1;
+
+        if self.duplicates == Duplicates::ByValue {
+            while self.next < self.order.len() && self.value(self.next) == self.value(pos) {
+                self.next += 1;
+            }
+        }
+    }
+}
+
+impl Iterator for KSums<'_> {
+    type Item = Combination;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.stack.len() == self.k {
+                let found = (self.sum == self.target).then(|| {
+                    let indices = self.stack.iter().map(|pos| self.order[*pos]).collect();
+
+                    Combination::new(self.numbers, indices)
+                });
+
+                self.backtrack();
+
+                if found.is_some() {
+                    return found;
+                }
+
+                continue;
+            }
+
+            let remaining = self.k - self.stack.len();
+            let exhausted = self.next + remaining > self.order.len()
+                || self.sum + self.value(self.next) * remaining as u128 > self.target;
+
+            if exhausted {
+                self.backtrack();
+            } else {
+                self.stack.push(self.next);
+                self.sum += self.value(self.next);
+                self.next += 1;
+            }
+        }
+
+        None
+    }
+}
+
+pub fn parse(reader: impl BufRead) -> ParseResult<Vec<u64>> {
+    parse::values(reader)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{k_sum, k_sum_all, k_sum_count, k_sum_iter, parse, Duplicates};
+
+    const EXAMPLE: &str = r#"
+        1721
+        979
+        366
+        299
+        675
+        1456
+    "#;
+
+    #[test]
+    fn test_k_sum() {
+        let numbers = parse(EXAMPLE.as_bytes()).unwrap();
+
+        let pair = k_sum(&numbers, 2, 2020).unwrap();
+        assert_eq!(vec![0, 3], pair.indices);
+        assert_eq!(vec![1721, 299], pair.values);
+
+        let triple = k_sum(&numbers, 3, 2020).unwrap();
+        assert_eq!(vec![1, 2, 4], triple.indices);
+        assert_eq!(241861950, triple.product());
+
+        let quadruple = k_sum(&numbers, 4, 3365).unwrap();
+        assert_eq!(3365, quadruple.sum());
+        assert_eq!(4, quadruple.indices.len());
+
+        assert_eq!(None, k_sum(&numbers, 5, 2020));
+    }
+
+    #[test]
+    fn test_k_sum_distinct_indices() {
+        assert_eq!(None, k_sum(&[1010, 5], 2, 2020));
+        assert_eq!(
+            vec![0, 2],
+            k_sum(&[1010, 5, 1010], 2, 2020).unwrap().indices
+        );
+        assert_eq!(None, k_sum(&[500, 505, 1010], 4, 2020));
+    }
+
+    #[test]
+    fn test_enumerate_combinations() {
+        let numbers = [1, 2, 3, 4, 5, 3];
+
+        let pairs = k_sum_all(&numbers, 2, 6, Duplicates::ByIndex)
+            .into_iter()
+            .map(|c| c.indices)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vec![0, 4], vec![1, 3], vec![2, 5]], pairs);
+
+        let pairs = k_sum_all(&numbers, 2, 6, Duplicates::ByValue)
+            .into_iter()
+            .map(|c| c.values)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vec![1, 5], vec![2, 4], vec![3, 3]], pairs);
+
+        let triples = k_sum_all(&numbers, 3, 9, Duplicates::ByIndex);
+        assert!(triples.iter().all(|c| c.sum() == 9));
+        assert_eq!(4, triples.len());
+        assert_eq!(2, k_sum_all(&numbers, 3, 9, Duplicates::ByValue).len());
+
+        assert_eq!(
+            Some(vec![0, 4]),
+            k_sum_iter(&numbers, 2, 6, Duplicates::ByIndex)
+                .next()
+                .map(|c| c.indices)
+        );
+        assert_eq!(0, k_sum_iter(&numbers, 7, 6, Duplicates::ByIndex).count());
+    }
+
+    #[test]
+    fn test_count_combinations() {
+        let numbers = [1, 2, 3, 4, 5, 3];
+
+        for k in 0..=numbers.len() {
+            for target in 0..=20 {
+                for duplicates in [Duplicates::ByIndex, Duplicates::ByValue].iter() {
+                    assert_eq!(
+                        k_sum_iter(&numbers, k, target, *duplicates).count() as u64,
+                        k_sum_count(&numbers, k, target, *duplicates),
+                        "k = {}, target = {}, {:?}",
+                        k,
+                        target,
+                        duplicates
+                    );
+                }
+            }
+        }
+
+        assert_eq!(10, k_sum_count(&[1; 5], 2, 2, Duplicates::ByIndex));
+        assert_eq!(1, k_sum_count(&[1; 5], 2, 2, Duplicates::ByValue));
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::io::BufRead;
+use std::ops::{Add, Sub};
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut numbers = parse(input.as_bytes())?;
+        numbers.sort_unstable();
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Self::Output1> {
+        let distribution = joltage_difference_distribution(&mut numbers.clone(), 0, 3, 3)?;
+
+        Ok(distribution.get(&1).unwrap_or(&0) * distribution.get(&3).unwrap_or(&0))
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Self::Output2> {
+        let goal = numbers
+            .last()
+            .ok_or("Missing adapters")?
+            .checked_add(3)
+            .ok_or("The device joltage is too large")?;
+
+        Ok(count_arrangements(0, goal, numbers, 3, &mut HashMap::new()))
+    }
+}
+
+pub fn parse(reader: impl BufRead) -> ParseResult<Vec<u8>> {
+    parse::values(reader)
+}
+
+pub fn joltage_difference_distribution<T>(
+    numbers: &mut [T],
+    input_joltage: T,
+    max_difference: T,
+    device_adapter_difference: T,
+) -> std::result::Result<HashMap<T, usize>, JoltageGap<T>>
+where
+    T: Sub<Output = T> + Add<Output = T> + Copy + Ord + Hash + Debug + Display,
+{
+    numbers.sort_unstable();
+
+    let mut distribution = HashMap::new();
+    let mut prev = input_joltage;
+
+    for curr in numbers {
+        let diff = *curr - prev;
+        if diff > max_difference {
+            return Err(JoltageGap {
+                from: prev,
+                to: *curr,
+                max_difference,
+            });
+        }
+
+        *distribution.entry(diff).or_default() += 1;
+        prev = *curr;
+    }
+
+    // device adapter
+    *distribution.entry(device_adapter_difference).or_default() += 1;
+
+    Ok(distribution)
+}
+
+/// Two consecutive joltages that can't be connected
+#[derive(ThisError, Debug, PartialEq)]
+#[error("The joltages {from} and {to} differ by more than {max_difference}")]
+pub struct JoltageGap<T: Debug + Display> {
+    pub from: T,
+    pub to: T,
+    pub max_difference: T,
+}
+
+pub fn count_arrangements<T>(
+    start: T,
+    goal: T,
+    numbers: &[T],
+    max_difference: T,
+    cache: &mut HashMap<T, usize>,
+) -> usize
+where
+    T: Sub<Output = T> + Add<Output = T> + Copy + Ord + Hash + Display,
+{
+    if let Some(&arrangements) = cache.get(&start) {
+        return arrangements;
+    }
+
+    let mut arrangements = if goal - start <= max_difference { 1 } else { 0 };
+
+    for (idx, &value) in numbers
+        .iter()
+        .take_while(|&&v| v - start <= max_difference)
+        .enumerate()
+    {
+        arrangements += count_arrangements(value, goal, &numbers[idx + 1..], max_difference, cache);
+    }
+
+    cache.insert(start, arrangements);
+    arrangements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_arrangements, joltage_difference_distribution, parse, JoltageGap};
+    use std::collections::HashMap;
+
+    const EXAMPLE_A: &str = r#"
+        16
+        10
+        15
+        5
+        1
+        11
+        7
+        19
+        6
+        12
+        4
+    "#;
+
+    const EXAMPLE_B: &str = r#"
+        28
+        33
+        18
+        42
+        31
+        14
+        46
+        20
+        48
+        47
+        24
+        23
+        49
+        45
+        19
+        38
+        39
+        11
+        1
+        32
+        25
+        35
+        8
+        17
+        7
+        9
+        4
+        2
+        34
+        10
+        3
+    "#;
+
+    #[test]
+    fn test_part1_example_a() {
+        let mut numbers = parse(EXAMPLE_A.as_bytes()).unwrap();
+        let distribution = joltage_difference_distribution(&mut numbers, 0, 3, 3).unwrap();
+
+        assert_eq!(7, distribution[&1]);
+        assert_eq!(5, distribution[&3]);
+    }
+
+    #[test]
+    fn test_part1_example_b() {
+        let mut numbers = parse(EXAMPLE_B.as_bytes()).unwrap();
+        let distribution = joltage_difference_distribution(&mut numbers, 0, 3, 3).unwrap();
+
+        assert_eq!(22, distribution[&1]);
+        assert_eq!(10, distribution[&3]);
+    }
+
+    #[test]
+    fn test_joltage_gap() {
+        assert_eq!(
+            Err(JoltageGap {
+                from: 1,
+                to: 5,
+                max_difference: 3
+            }),
+            joltage_difference_distribution(&mut [5, 1], 0, 3, 3)
+        );
+    }
+
+    #[test]
+    fn test_part2_example_a() {
+        let mut numbers = parse(EXAMPLE_A.as_bytes()).unwrap();
+        numbers.sort_unstable();
+
+        let arrangements = count_arrangements(
+            0,
+            numbers.last().unwrap() + 3,
+            &numbers,
+            3,
+            &mut HashMap::new(),
+        );
+
+        assert_eq!(8, arrangements);
+    }
+
+    #[test]
+    fn test_part2_example_b() {
+        let mut numbers = parse(EXAMPLE_B.as_bytes()).unwrap();
+        numbers.sort_unstable();
+
+        let arrangements = count_arrangements(
+            0,
+            numbers.last().unwrap() + 3,
+            &numbers,
+            3,
+            &mut HashMap::new(),
+        );
+
+        assert_eq!(19208, arrangements);
+    }
+}
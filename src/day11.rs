@@ -0,0 +1,198 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::Formatter;
+use std::io::BufRead;
+use thiserror::Error as ThisError;
+
+use crate::grid::{Grid, Neighbourhood, Point};
+use crate::parse::ParseResult;
+use crate::solver::{Result, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = SeatLayout;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_seat_layout(input.as_bytes())?)
+    }
+
+    fn part1(seat_layout: &Self::Input) -> Result<Self::Output1> {
+        let mut seat_layout = seat_layout.clone();
+        apply_seating_rules(&mut seat_layout, SeatingRules::Adjacent);
+
+        Ok(count_occupied_seats(&seat_layout))
+    }
+
+    fn part2(seat_layout: &Self::Input) -> Result<Self::Output2> {
+        let mut seat_layout = seat_layout.clone();
+        apply_seating_rules(&mut seat_layout, SeatingRules::Visible);
+
+        Ok(count_occupied_seats(&seat_layout))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum GridPlace {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl TryFrom<char> for GridPlace {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Self::Floor,
+            'L' => Self::EmptySeat,
+            '#' => Self::OccupiedSeat,
+            c => return Err(ParseError::InvalidGridPlace(c)),
+        })
+    }
+}
+
+impl fmt::Display for GridPlace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Floor => ".",
+                Self::EmptySeat => "L",
+                Self::OccupiedSeat => "#",
+            }
+        )
+    }
+}
+
+pub type SeatLayout = Grid<GridPlace>;
+
+pub fn parse_seat_layout(reader: impl BufRead) -> ParseResult<SeatLayout> {
+    Grid::parse(reader, GridPlace::try_from)
+}
+
+/// The first seat in each of the eight directions, floor places are skipped
+pub fn visible_seats(seat_layout: &SeatLayout, pos: Point) -> impl Iterator<Item = &GridPlace> {
+    Neighbourhood::Eight
+        .directions()
+        .iter()
+        .filter_map(move |direction| {
+            seat_layout
+                .ray(pos, *direction)
+                .map(|(_pos, place)| place)
+                .find(|place| !matches!(place, GridPlace::Floor))
+        })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SeatingRules {
+    /// Only the eight directly adjacent places are considered, four occupied
+    /// neighbours empty a seat
+    Adjacent,
+    /// The first seat in each of the eight directions is considered, five occupied
+    /// neighbours empty a seat
+    Visible,
+}
+
+impl SeatingRules {
+    pub fn occupied_neighbours(&self, seat_layout: &SeatLayout, pos: Point) -> usize {
+        let is_occupied = |place: &&GridPlace| matches!(place, GridPlace::OccupiedSeat);
+
+        match self {
+            Self::Adjacent => seat_layout
+                .neighbours(pos, Neighbourhood::Eight)
+                .map(|(_pos, place)| place)
+                .filter(is_occupied)
+                .count(),
+            Self::Visible => visible_seats(seat_layout, pos).filter(is_occupied).count(),
+        }
+    }
+
+    pub fn tolerance(&self) -> usize {
+        match self {
+            Self::Adjacent => 4,
+            Self::Visible => 5,
+        }
+    }
+}
+
+pub fn apply_seating_rules(seat_layout: &mut SeatLayout, rules: SeatingRules) {
+    loop {
+        let changeset = seat_layout
+            .iter()
+            .filter_map(|(pos, place)| {
+                let occupied = rules.occupied_neighbours(seat_layout, pos);
+
+                let new_place = match place {
+                    GridPlace::EmptySeat if occupied == 0 => GridPlace::OccupiedSeat,
+                    GridPlace::OccupiedSeat if occupied >= rules.tolerance() => {
+                        GridPlace::EmptySeat
+                    }
+                    _ => return None,
+                };
+
+                Some((pos, new_place))
+            })
+            .collect::<Vec<_>>();
+
+        if changeset.is_empty() {
+            break;
+        }
+
+        for (pos, place) in changeset {
+            *seat_layout.get_mut(pos).unwrap() = place;
+        }
+    }
+}
+
+pub fn count_occupied_seats(seat_layout: &SeatLayout) -> usize {
+    seat_layout
+        .iter()
+        .filter(|(_pos, place)| matches!(place, GridPlace::OccupiedSeat))
+        .count()
+}
+
+#[derive(ThisError, Debug)]
+pub enum ParseError {
+    #[error("invalid grid place: {0}")]
+    InvalidGridPlace(char),
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_seating_rules, count_occupied_seats, parse_seat_layout, SeatingRules};
+
+    const EXAMPLE: &str = r#"
+        L.LL.LL.LL
+        LLLLLLL.LL
+        L.L.L..L..
+        LLLL.LL.LL
+        L.LL.LL.LL
+        L.LLLLL.LL
+        ..L.L.....
+        LLLLLLLLLL
+        L.LLLLLL.L
+        L.LLLLL.LL
+    "#;
+
+    #[test]
+    fn test_apply_adjacent_seating_rules() {
+        let mut seat_layout = parse_seat_layout(EXAMPLE.as_bytes()).unwrap();
+        apply_seating_rules(&mut seat_layout, SeatingRules::Adjacent);
+
+        let occupied_seats = count_occupied_seats(&seat_layout);
+        assert_eq!(37, occupied_seats);
+    }
+
+    #[test]
+    fn test_apply_visible_seating_rules() {
+        let mut seat_layout = parse_seat_layout(EXAMPLE.as_bytes()).unwrap();
+        apply_seating_rules(&mut seat_layout, SeatingRules::Visible);
+
+        let occupied_seats = count_occupied_seats(&seat_layout);
+        assert_eq!(26, occupied_seats);
+    }
+}
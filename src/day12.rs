@@ -0,0 +1,326 @@
+use std::cmp::Ordering;
+use std::convert::{TryFrom, TryInto};
+use std::io::BufRead;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<NavigationInstruction>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_instructions(input.as_bytes())?)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Self::Output1> {
+        let mut ship = Ship::new(Point { x: 0, y: 0 }, Direction::East);
+        ship.follow_navigation_instructions(instructions)?;
+
+        Ok(ship.calc_manhattan_distance())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Self::Output2> {
+        let mut ship = Ship::new(Point { x: 0, y: 0 }, Direction::East);
+        ship.follow_waypoint_instructions(instructions);
+
+        Ok(ship.calc_manhattan_distance())
+    }
+}
+
+pub fn parse_instructions(reader: impl BufRead) -> ParseResult<Vec<NavigationInstruction>> {
+    parse::values(reader)
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl Point<i32> {
+    pub fn rotate(&self, angle: f32) -> Self {
+        let s = angle.sin();
+        let c = angle.cos();
+
+        Point {
+            x: (self.x as f32 * c + self.y as f32 * s).round() as i32,
+            y: (-(self.x as f32) * s + self.y as f32 * c).round() as i32,
+        }
+    }
+}
+
+pub struct Ship {
+    pub location: Point<i32>,
+    pub orientation: Direction,
+    pub waypoint: Point<i32>,
+    pub starting_location: Point<i32>,
+}
+
+impl Ship {
+    pub fn new(starting_location: Point<i32>, orientation: Direction) -> Self {
+        Self {
+            location: starting_location.clone(),
+            starting_location,
+            orientation,
+            waypoint: Point { x: 10, y: 1 },
+        }
+    }
+
+    pub fn calc_manhattan_distance(&self) -> u32 {
+        (self.location.x - self.starting_location.x).unsigned_abs()
+            + (self.location.y - self.starting_location.y).unsigned_abs()
+    }
+
+    pub fn follow_navigation_instructions(
+        &mut self,
+        instructions: &[NavigationInstruction],
+    ) -> Result<(), InvalidHeading> {
+        instructions.iter().try_for_each(|v| self.navigate(v))
+    }
+
+    pub fn follow_waypoint_instructions(&mut self, instructions: &[NavigationInstruction]) {
+        instructions.iter().for_each(|v| self.navigate_waypoint(v));
+    }
+
+    pub fn navigate(&mut self, instruction: &NavigationInstruction) -> Result<(), InvalidHeading> {
+        match &instruction.action {
+            NavigationAction::Turn(dir) => {
+                let change = i8::from(dir) as i16 * instruction.value as i16;
+                self.orientation = (u16::from(&self.orientation) as i16 + change).try_into()?;
+            }
+            NavigationAction::Move(m) => {
+                let multiplier = match m {
+                    NavigationActionMove::Absolute(dir) => dir,
+                    NavigationActionMove::Forward => &self.orientation,
+                }
+                .as_point_offset_multiplier();
+
+                self.location.x += instruction.value as i32 * multiplier.x as i32;
+                self.location.y += instruction.value as i32 * multiplier.y as i32;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn navigate_waypoint(&mut self, instruction: &NavigationInstruction) {
+        match &instruction.action {
+            NavigationAction::Turn(dir) => {
+                let angle = (i8::from(dir) as i16 * instruction.value as i16) as f32;
+
+                self.waypoint = self.waypoint.rotate(angle.to_radians());
+            }
+            NavigationAction::Move(NavigationActionMove::Absolute(dir)) => {
+                let multiplier = dir.as_point_offset_multiplier();
+
+                self.waypoint.x += instruction.value as i32 * multiplier.x as i32;
+                self.waypoint.y += instruction.value as i32 * multiplier.y as i32;
+            }
+            NavigationAction::Move(NavigationActionMove::Forward) => {
+                for _ in 0..instruction.value {
+                    self.location.x += self.waypoint.x;
+                    self.location.y += self.waypoint.y;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub fn as_point_offset_multiplier(&self) -> &Point<i8> {
+        match self {
+            Direction::North => &Point { x: 0, y: 1 },
+            Direction::South => &Point { x: 0, y: -1 },
+            Direction::East => &Point { x: 1, y: 0 },
+            Direction::West => &Point { x: -1, y: 0 },
+        }
+    }
+}
+
+impl TryFrom<i16> for Direction {
+    type Error = InvalidHeading;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        use Direction::*;
+
+        let value = value % 360;
+        let value = match value.cmp(&0) {
+            Ordering::Less => value + 360,
+            _ => value,
+        };
+
+        Ok(match value {
+            0 => North,
+            90 => East,
+            180 => South,
+            270 => West,
+            _ => return Err(InvalidHeading(value)),
+        })
+    }
+}
+
+impl From<&Direction> for u16 {
+    fn from(v: &Direction) -> Self {
+        match v {
+            Direction::North => 0,
+            Direction::South => 180,
+            Direction::East => 90,
+            Direction::West => 270,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NavigationInstruction {
+    pub value: u16,
+    pub action: NavigationAction,
+}
+
+impl FromStr for NavigationInstruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let action = s.get(..1).unwrap_or("").parse()?;
+        let mut value = s.get(1..).unwrap_or("").parse()?;
+
+        if let NavigationAction::Turn(_) = action {
+            if value % 90 != 0 {
+                return Err(ParseError::InvalidTurnAngle(value));
+            }
+
+            // full turns don't change anything, this keeps the angle within an i16
+            value %= 360;
+        }
+
+        Ok(Self { action, value })
+    }
+}
+
+#[derive(Debug)]
+pub enum NavigationAction {
+    Turn(TurnDirection),
+    Move(NavigationActionMove),
+}
+
+#[derive(Debug)]
+pub enum TurnDirection {
+    Left,
+    Right,
+}
+
+impl From<&TurnDirection> for i8 {
+    fn from(v: &TurnDirection) -> Self {
+        match v {
+            TurnDirection::Left => -1,
+            TurnDirection::Right => 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum NavigationActionMove {
+    Absolute(Direction),
+    Forward,
+}
+
+impl FromStr for NavigationAction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Direction::*;
+        use NavigationAction::*;
+        use NavigationActionMove::*;
+        use TurnDirection::*;
+
+        Ok(match s {
+            "N" => Move(Absolute(North)),
+            "S" => Move(Absolute(South)),
+            "E" => Move(Absolute(East)),
+            "W" => Move(Absolute(West)),
+            "L" => Turn(Left),
+            "R" => Turn(Right),
+            "F" => Move(Forward),
+            s => return Err(ParseError::UnrecognizedNavigationAction(String::from(s))),
+        })
+    }
+}
+
+#[derive(ThisError, Debug)]
+pub enum ParseError {
+    #[error("Unrecognized navigation action {0}")]
+    UnrecognizedNavigationAction(String),
+    #[error("Turn angle {0} is not a multiple of 90 degrees")]
+    InvalidTurnAngle(u16),
+    #[error(transparent)]
+    ParseIntError(#[from] ParseIntError),
+}
+
+#[derive(ThisError, Debug)]
+#[error("Heading {0} is not a multiple of 90 degrees")]
+pub struct InvalidHeading(i16);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_instructions, Direction, Point, Ship};
+
+    const EXAMPLE: &str = r#"
+        F10
+        N3
+        F7
+        R90
+        F11
+    "#;
+
+    #[test]
+    fn test_navigation() {
+        let instructions = parse_instructions(EXAMPLE.as_bytes()).unwrap();
+        let mut ship = Ship::new(Point::default(), Direction::East);
+
+        ship.follow_navigation_instructions(&instructions).unwrap();
+
+        assert_eq!(25, ship.calc_manhattan_distance())
+    }
+
+    #[test]
+    fn test_full_turns() {
+        let instructions = parse_instructions("R65520\nL450\nF1".as_bytes()).unwrap();
+        let mut ship = Ship::new(Point::default(), Direction::East);
+
+        ship.follow_navigation_instructions(&instructions).unwrap();
+
+        assert_eq!(Point { x: 0, y: 1 }, ship.location);
+    }
+
+    #[test]
+    fn test_waypoint_navigation() {
+        let instructions = parse_instructions(EXAMPLE.as_bytes()).unwrap();
+        let mut ship = Ship::new(Point::default(), Direction::East);
+
+        ship.follow_waypoint_instructions(&instructions);
+
+        assert_eq!(286, ship.calc_manhattan_distance())
+    }
+
+    #[test]
+    fn test_rotation() {
+        let x: Point<i32> = Point { x: 10, y: 4 };
+        let r = x.rotate(90f32.to_radians());
+
+        assert_eq!(Point { x: 4, y: -10 }, r);
+    }
+}
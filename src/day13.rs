@@ -0,0 +1,239 @@
+use std::io::BufRead;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Option<u32>, Vec<Option<u32>>);
+    type Output1 = u32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1((arrival, bus_ids): &Self::Input) -> Result<Self::Output1> {
+        let arrival = &arrival.ok_or("Missing the arrival timestamp")?;
+        let bus_ids = bus_ids.iter().flatten().copied().collect::<Vec<_>>();
+        let (bus_id, departure) =
+            determine_earliest_bus(arrival, &bus_ids).ok_or("Couldn't find any bus")?;
+
+        Ok(bus_id * (departure - arrival))
+    }
+
+    fn part2((_, bus_ids): &Self::Input) -> Result<Self::Output2> {
+        Ok(find_earliest_timestamp(bus_ids)?)
+    }
+}
+
+/// Parses the arrival timestamp and the bus ids, the arrival timestamp is optional as only
+/// part 1 needs it
+pub fn parse(reader: impl BufRead) -> ParseResult<(Option<u32>, Vec<Option<u32>>)> {
+    let lines = parse::lines(reader)
+        .filter(|line| !matches!(line, Ok((_, l)) if l.trim().is_empty()))
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    let (arrival, (idx, line)) = match lines.as_slice() {
+        [] => return Err(ParseError::new(0, "", "", "Missing the bus ids")),
+        [buses] => (None, buses),
+        [(idx, line), buses] => {
+            let arrival = line
+                .trim()
+                .parse::<u32>()
+                .map_err(|e| ParseError::new(*idx, line, line.trim(), e))?;
+
+            (Some(arrival), buses)
+        }
+        [_, _, (idx, line), ..] => {
+            return Err(ParseError::new(
+                *idx,
+                line,
+                line.trim(),
+                "Expected at most the arrival timestamp and the bus ids",
+            ))
+        }
+    };
+
+    let bus_ids = line
+        .trim()
+        .split(',')
+        .map(|s| match s {
+            "x" => Ok(None),
+            s => match s.parse::<u32>() {
+                Ok(0) => Err(ParseError::new(*idx, line, s, "Bus ids must be positive")),
+                Ok(id) => Ok(Some(id)),
+                Err(e) => Err(ParseError::new(*idx, line, s, e)),
+            },
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    Ok((arrival, bus_ids))
+}
+
+pub fn determine_earliest_bus<'a>(arrival: &u32, bus_ids: &'a [u32]) -> Option<(&'a u32, u32)> {
+    bus_ids
+        .iter()
+        .map(|id| {
+            let x = (*arrival as f32 / *id as f32).ceil() as u32;
+
+            (id, id * x)
+        })
+        .min_by_key(|(_id, departure)| *departure)
+}
+
+/// The earliest positive timestamp at which every bus departs at its offset in the list
+///
+/// Fails if there is no bus, if the bus ids aren't pairwise coprime or if the timestamp
+/// doesn't fit into an u64.
+pub fn find_earliest_timestamp(bus_ids: &[Option<u32>]) -> std::result::Result<u64, ScheduleError> {
+    let mut buses = bus_ids
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, id)| id.map(|id| (offset as u64, id as u64)));
+
+    let (offset, first_bus) = buses.next().ok_or(ScheduleError::NoBuses)?;
+    let mut timestamp = (first_bus - offset % first_bus) % first_bus;
+    let mut step = first_bus;
+
+    // thanks to u/PillarsBliz for the algo
+    for (offset, id) in buses {
+        if gcd(step, id) != 1 {
+            return Err(ScheduleError::NotCoprime(id));
+        }
+
+        while (timestamp + offset) % id != 0 {
+            timestamp = timestamp.checked_add(step).ok_or(ScheduleError::Overflow)?;
+        }
+
+        step = step.checked_mul(id).ok_or(ScheduleError::Overflow)?;
+    }
+
+    // every multiple of step apart from 0 is a solution as well
+    Ok(if timestamp == 0 { step } else { timestamp })
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+#[derive(ThisError, Debug, PartialEq)]
+pub enum ScheduleError {
+    #[error("There are no buses")]
+    NoBuses,
+    #[error("The bus id {0} shares a factor with a previous bus id, the ids have to be coprime")]
+    NotCoprime(u64),
+    #[error("The timestamp is too large")]
+    Overflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{determine_earliest_bus, find_earliest_timestamp, parse, ScheduleError};
+
+    #[test]
+    fn test_determine_earliest_bus() {
+        let data = r#"939
+            7,13,x,x,59,x,31,19"#;
+
+        let (arrival, bus_ids) = parse(data.as_bytes()).unwrap();
+        let arrival = arrival.unwrap();
+        let bus_ids = bus_ids.into_iter().flatten().collect::<Vec<_>>();
+        let (bus_id, departure) = determine_earliest_bus(&arrival, &bus_ids).unwrap();
+
+        assert_eq!(295, bus_id * (departure - arrival));
+    }
+
+    #[test]
+    fn test_find_earliest_timestamp() {
+        let data = r#"939
+            7,13,x,x,59,x,31,19"#;
+
+        let (_, bus_ids) = parse(data.as_bytes()).unwrap();
+        let timestamp = find_earliest_timestamp(&bus_ids).unwrap();
+        assert_eq!(1068781, timestamp);
+
+        let data = r#"939
+            17,x,13,19"#;
+
+        let (_, bus_ids) = parse(data.as_bytes()).unwrap();
+        let timestamp = find_earliest_timestamp(&bus_ids).unwrap();
+        assert_eq!(3417, timestamp);
+
+        let data = r#"939
+            67,7,59,61"#;
+
+        let (_, bus_ids) = parse(data.as_bytes()).unwrap();
+        let timestamp = find_earliest_timestamp(&bus_ids).unwrap();
+        assert_eq!(754018, timestamp);
+
+        let data = r#"939
+            67,x,7,59,61"#;
+
+        let (_, bus_ids) = parse(data.as_bytes()).unwrap();
+        let timestamp = find_earliest_timestamp(&bus_ids).unwrap();
+        assert_eq!(779210, timestamp);
+
+        let data = r#"939
+            67,7,x,59,61"#;
+
+        let (_, bus_ids) = parse(data.as_bytes()).unwrap();
+        let timestamp = find_earliest_timestamp(&bus_ids).unwrap();
+        assert_eq!(1261476, timestamp);
+
+        let data = r#"939
+            1789,37,47,1889"#;
+
+        let (_, bus_ids) = parse(data.as_bytes()).unwrap();
+        let timestamp = find_earliest_timestamp(&bus_ids).unwrap();
+        assert_eq!(1202161486, timestamp);
+    }
+
+    #[test]
+    fn test_parse_without_arrival() {
+        let (arrival, bus_ids) = parse("7,13,x,x,59,x,31,19\n".as_bytes()).unwrap();
+
+        assert_eq!(None, arrival);
+        assert_eq!(Ok(1068781), find_earliest_timestamp(&bus_ids));
+        assert!(parse("939\n7,13\n7\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_schedules() {
+        assert_eq!(Ok(6), find_earliest_timestamp(&[None, Some(7)]));
+        assert_eq!(Ok(7), find_earliest_timestamp(&[Some(7)]));
+        assert_eq!(
+            Err(ScheduleError::NoBuses),
+            find_earliest_timestamp(&[None])
+        );
+        assert_eq!(
+            Err(ScheduleError::NotCoprime(6)),
+            find_earliest_timestamp(&[Some(4), Some(6)])
+        );
+        assert_eq!(
+            Err(ScheduleError::Overflow),
+            find_earliest_timestamp(
+                &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53]
+                    .iter()
+                    .map(|id| Some(*id))
+                    .collect::<Vec<_>>()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_bus_id() {
+        let data = r#"939
+            7,13,y,x"#;
+
+        let error = parse(data.as_bytes()).unwrap_err();
+
+        assert_eq!((2, 18), (error.line, error.column));
+        assert_eq!("y", error.text);
+    }
+}
@@ -0,0 +1,271 @@
+use scan_fmt::scan_fmt;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Self::Output1> {
+        Ok(execute(instructions))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Self::Output2> {
+        Ok(execute_floating(instructions))
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mask(String),
+    Write { address: u64, value: u64 },
+}
+
+pub fn parse(reader: impl BufRead) -> ParseResult<Vec<Instruction>> {
+    parse::lines(reader)
+        .filter(|line| !matches!(line, Ok((_, l)) if l.trim().is_empty()))
+        .map(|line| {
+            let (idx, line) = line?;
+            let l = line.trim();
+
+            if let Ok(mask) = scan_fmt!(l, "mask = {}", String) {
+                if mask.len() > 36 || mask.chars().any(|c| !matches!(c, '0' | '1' | 'X')) {
+                    return Err(ParseError::new(
+                        idx,
+                        &line,
+                        &mask,
+                        "Expected a mask of up to 36 0, 1 or X characters",
+                    ));
+                }
+
+                return Ok(Instruction::Mask(mask));
+            }
+
+            scan_fmt!(l, "mem[{d}] = {d}", u64, u64)
+                .map(|(address, value)| Instruction::Write { address, value })
+                .map_err(|_| {
+                    ParseError::new(
+                        idx,
+                        &line,
+                        l,
+                        "Expected either \"mask = <mask>\" or \"mem[<address>] = <value>\"",
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Overwrites the bits of a value, the decoder chip version 1
+#[derive(Debug, Default)]
+pub struct Bitmask {
+    zeros: u64,
+    ones: u64,
+}
+
+impl FromStr for Bitmask {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut zeros = 0;
+        let mut ones = 0;
+
+        for c in s.trim().chars() {
+            zeros <<= 1;
+            ones <<= 1;
+
+            match c {
+                '1' => ones |= 0b1,
+                '0' => zeros |= 0b1,
+                'X' => {}
+                _ => return Err(()),
+            }
+        }
+
+        Ok(Self { zeros, ones })
+    }
+}
+
+impl Bitmask {
+    pub fn apply(&self, value: u64) -> u64 {
+        (value & !self.zeros) | self.ones
+    }
+}
+
+/// Expands a memory address into all floating variations, the decoder chip version 2
+#[derive(Debug, Default)]
+pub struct FloatingBitmask {
+    floating: Vec<u32>,
+    ones: u64,
+}
+
+impl FromStr for FloatingBitmask {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut ones = 0u64;
+        let bitlen = s.len();
+        let mut floating = Vec::new();
+
+        for (idx, c) in s.chars().enumerate() {
+            ones <<= 1;
+
+            match c {
+                '1' => ones |= 0b1,
+                '0' => {}
+                'X' => floating.push((bitlen - idx - 1) as u32),
+                _ => return Err(()),
+            }
+        }
+
+        Ok(Self { floating, ones })
+    }
+}
+
+impl FloatingBitmask {
+    pub fn apply(&self, value: u64) -> Vec<u64> {
+        let value = value | self.ones;
+
+        fn apply_floating(value: u64, floating: &[u32], output: &mut Vec<u64>) {
+            if floating.is_empty() {
+                return;
+            }
+
+            let pos = floating[0];
+
+            // floating variation forced one
+            let forced_one = value | (0b1 << pos);
+            // floating variation forced zero
+            let forced_zero = value & !(0b1 << pos);
+
+            if floating.len() == 1 {
+                output.push(forced_one);
+                output.push(forced_zero);
+            } else {
+                apply_floating(forced_one, &floating[1..], output);
+                apply_floating(forced_zero, &floating[1..], output);
+            }
+        }
+
+        let mut output = Vec::new();
+        apply_floating(value, &self.floating, &mut output);
+
+        output
+    }
+}
+
+pub fn execute(instructions: &[Instruction]) -> u64 {
+    let mut mem = HashMap::new();
+    let mut mask = Bitmask::default();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(string_mask) => mask = string_mask.parse().unwrap(),
+            Instruction::Write { address, value } => {
+                mem.insert(*address, mask.apply(*value));
+            }
+        }
+    }
+
+    mem.values().sum()
+}
+
+pub fn execute_floating(instructions: &[Instruction]) -> u64 {
+    let mut mem = HashMap::new();
+    let mut mask = FloatingBitmask::default();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(string_mask) => mask = string_mask.parse().unwrap(),
+            Instruction::Write { address, value } => {
+                for address in mask.apply(*address) {
+                    mem.insert(address, *value);
+                }
+            }
+        }
+    }
+
+    mem.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{execute, execute_floating, parse, Bitmask, FloatingBitmask};
+
+    #[test]
+    fn test_bitmask() {
+        let data = [
+            (11, "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X", 73),
+            (101, "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X", 101),
+            (0, "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X", 64),
+        ];
+
+        for (value, mask, result) in &data {
+            let bitmask = mask.parse::<Bitmask>().unwrap();
+
+            assert_eq!(*result, bitmask.apply(*value));
+        }
+    }
+
+    #[test]
+    fn test_execute() {
+        let data = r#"
+            mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+            mem[8] = 11
+            mem[7] = 101
+            mem[8] = 0
+        "#;
+
+        let result = execute(&parse(data.as_bytes()).unwrap());
+        assert_eq!(165, result);
+    }
+
+    #[test]
+    fn test_floating_bitmask() {
+        let data: &[(u64, &str, &[u64])] = &[
+            (
+                42,
+                "000000000000000000000000000000X1001X",
+                &[26, 27, 58, 59],
+            ),
+            (
+                26,
+                "00000000000000000000000000000000X0XX",
+                &[16, 17, 18, 19, 24, 25, 26, 27],
+            ),
+        ];
+
+        for (value, mask, expected_result) in data {
+            let bitmask = mask.parse::<FloatingBitmask>().unwrap();
+
+            let mut result = bitmask.apply(*value);
+            result.sort_unstable();
+
+            assert_eq!(*expected_result, result);
+        }
+    }
+
+    #[test]
+    fn test_execute_floating() {
+        let data = r#"
+            mask = 000000000000000000000000000000X1001X
+            mem[42] = 100
+            mask = 00000000000000000000000000000000X0XX
+            mem[26] = 1
+        "#;
+
+        let result = execute_floating(&parse(data.as_bytes()).unwrap());
+        assert_eq!(208, result);
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1(starting_numbers: &Self::Input) -> Result<Self::Output1> {
+        Ok(play_game(starting_numbers, 2020))
+    }
+
+    fn part2(starting_numbers: &Self::Input) -> Result<Self::Output2> {
+        Ok(play_game(starting_numbers, 30_000_000))
+    }
+}
+
+pub fn parse(input: impl BufRead) -> ParseResult<Vec<u64>> {
+    let (idx, line) = parse::lines(input)
+        .find(|line| !matches!(line, Ok((_, l)) if l.trim().is_empty()))
+        .ok_or_else(|| ParseError::new(0, "", "", "Missing the starting numbers"))??;
+
+    line.trim()
+        .split(',')
+        .map(|s| {
+            s.trim()
+                .parse::<u64>()
+                .map_err(|e| ParseError::new(idx, &line, s, e))
+        })
+        .collect()
+}
+
+pub fn play_game(starting_numbers: &[u64], rounds: usize) -> u64 {
+    let mut memory = Memory::new(rounds.max(starting_numbers.len()));
+    let mut speak = |n: u64, round: usize| {
+        memory
+            .replace(n, round as u32)
+            .map_or(0, |prev_round| (round as u32 - prev_round) as u64)
+    };
+
+    let mut last_number = 0;
+    let mut next_number = 0;
+
+    for (round, &n) in starting_numbers.iter().enumerate() {
+        last_number = n;
+        next_number = speak(n, round);
+    }
+
+    for round in starting_numbers.len()..rounds {
+        last_number = next_number;
+        next_number = speak(next_number, round);
+    }
+
+    last_number
+}
+
+/// The round each number was last spoken in
+///
+/// Apart from the starting numbers every spoken number is the age of a number and
+/// therefore smaller than the round count. Larger starting numbers are kept aside as they
+/// are never spoken again after the start.
+struct Memory {
+    rounds: Vec<u32>,
+    large_numbers: HashMap<u64, u32>,
+}
+
+impl Memory {
+    const UNSPOKEN: u32 = u32::MAX;
+
+    fn new(rounds: usize) -> Self {
+        assert!(
+            rounds < Self::UNSPOKEN as usize,
+            "Too many rounds to play: {}",
+            rounds
+        );
+
+        Self {
+            rounds: vec![Self::UNSPOKEN; rounds],
+            large_numbers: HashMap::new(),
+        }
+    }
+
+    fn replace(&mut self, n: u64, round: u32) -> Option<u32> {
+        if n >= self.rounds.len() as u64 {
+            return self.large_numbers.insert(n, round);
+        }
+
+        Some(std::mem::replace(&mut self.rounds[n as usize], round))
+            .filter(|prev_round| *prev_round != Self::UNSPOKEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, play_game};
+
+    #[test]
+    fn test_play_game() {
+        let data = &[
+            ("1,3,2", 2020, 1),
+            ("2,1,3", 2020, 10),
+            ("1,2,3", 2020, 27),
+            ("2,3,1", 2020, 78),
+            ("3,2,1", 2020, 438),
+            ("3,1,2", 2020, 1836),
+        ];
+
+        for (s, rounds, expected) in data {
+            assert_eq!(
+                *expected,
+                play_game(&parse(s.as_bytes()).unwrap(), *rounds as usize)
+            );
+        }
+
+        // starting numbers beyond the round count are never spoken again but may repeat
+        assert_eq!(0, play_game(&[0, 3, 99_999_999_999], 4));
+        assert_eq!(2, play_game(&[99_999_999_999, 1, 99_999_999_999], 4));
+    }
+}
@@ -0,0 +1,318 @@
+use scan_fmt::scan_fmt;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Details, Result, Solution};
+
+pub type FieldRules = HashMap<String, [RangeInclusive<u32>; 2]>;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (FieldRules, Vec<u32>, Vec<Vec<u32>>);
+    type Output1 = u32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1((field_rules, _my_ticket, nearby_tickets): &Self::Input) -> Result<Self::Output1> {
+        Ok(determine_ticket_scanning_error_rate(
+            field_rules,
+            nearby_tickets,
+        ))
+    }
+
+    fn part2((field_rules, my_ticket, nearby_tickets): &Self::Input) -> Result<Self::Output2> {
+        let field_positions = determine_valid_field_positions(field_rules, nearby_tickets)?;
+
+        Ok(field_positions
+            .iter()
+            .filter(|(field, _pos)| field.starts_with("departure"))
+            .map(|(_field, &pos)| my_ticket[pos] as u64)
+            .product())
+    }
+
+    fn details2((field_rules, _my_ticket, nearby_tickets): &Self::Input) -> Result<Details> {
+        let field_positions = determine_valid_field_positions(field_rules, nearby_tickets)?
+            .into_iter()
+            .map(|(field, pos)| (field, pos.into()))
+            .collect::<Details>();
+
+        let mut details = Details::new();
+        details.insert(String::from("field_positions"), field_positions.into());
+
+        Ok(details)
+    }
+}
+
+/// Determines the field positions using only the tickets without invalid values
+pub fn determine_valid_field_positions(
+    field_rules: &FieldRules,
+    tickets: &[Vec<u32>],
+) -> std::result::Result<HashMap<String, usize>, AmbiguousFields> {
+    let valid_tickets = tickets
+        .iter()
+        .filter(|t| validate_ticket(field_rules, t))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    determine_field_positions(field_rules, &valid_tickets)
+}
+
+pub fn parse(reader: impl BufRead) -> ParseResult<(FieldRules, Vec<u32>, Vec<Vec<u32>>)> {
+    enum State {
+        Rules,
+        MyTicket,
+        NearbyTickets,
+    }
+
+    let mut state = State::Rules;
+    let (mut rules, mut my_ticket, mut nearby_tickets) = (HashMap::new(), Vec::new(), Vec::new());
+    let (mut my_ticket_idx, mut last_idx) = (None, 0);
+
+    for line in parse::lines(reader) {
+        let (idx, raw_line) = line?;
+        let line = raw_line.trim();
+        last_idx = idx;
+        match state {
+            _ if line.is_empty() => {}
+            _ if line.starts_with("your ticket") => {
+                state = State::MyTicket;
+                my_ticket_idx = Some(idx);
+            }
+            _ if line.starts_with("nearby tickets") => {
+                state = State::NearbyTickets;
+            }
+            State::Rules => {
+                let (class, from_a, to_a, from_b, to_b) = scan_fmt!(
+                    line,
+                    "{[^:]}: {d}-{d} or {d}-{d}",
+                    String,
+                    u32,
+                    u32,
+                    u32,
+                    u32
+                )
+                .map_err(|_| {
+                    ParseError::new(
+                        idx,
+                        &raw_line,
+                        line,
+                        "Expected a rule like \"<field>: <from>-<to> or <from>-<to>\"",
+                    )
+                })?;
+
+                rules.insert(
+                    class,
+                    [
+                        RangeInclusive::new(from_a, to_a),
+                        RangeInclusive::new(from_b, to_b),
+                    ],
+                );
+            }
+            State::MyTicket | State::NearbyTickets => {
+                let ticket: Vec<u32> = line
+                    .split(',')
+                    .map(|s| {
+                        s.parse::<u32>()
+                            .map_err(|e| ParseError::new(idx, &raw_line, s, e))
+                    })
+                    .collect::<ParseResult<Vec<u32>>>()?;
+
+                if ticket.len() != rules.len() {
+                    return Err(ParseError::new(
+                        idx,
+                        &raw_line,
+                        line,
+                        format!("Expected a ticket with {} values", rules.len()),
+                    ));
+                }
+
+                match state {
+                    State::MyTicket => my_ticket = ticket,
+                    State::NearbyTickets => nearby_tickets.push(ticket),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    if my_ticket.is_empty() {
+        return Err(match my_ticket_idx {
+            Some(idx) => ParseError::new(idx, "", "", "Expected a ticket after \"your ticket:\""),
+            None => ParseError::new(last_idx, "", "", "Missing the \"your ticket:\" section"),
+        });
+    }
+
+    Ok((rules, my_ticket, nearby_tickets))
+}
+
+pub fn determine_ticket_scanning_error_rate(field_rules: &FieldRules, tickets: &[Vec<u32>]) -> u32 {
+    tickets.iter().fold(0, |error_rate, ticket| {
+        error_rate
+            + ticket
+                .iter()
+                .filter(|n| {
+                    !field_rules
+                        .values()
+                        .any(|r| r[0].contains(n) || r[1].contains(n))
+                })
+                .sum::<u32>()
+    })
+}
+
+pub fn validate_ticket(field_rules: &FieldRules, ticket: &[u32]) -> bool {
+    ticket.iter().all(|n| {
+        field_rules
+            .values()
+            .any(|r| r.iter().any(|r| r.contains(n)))
+    })
+}
+
+pub fn determine_field_positions(
+    field_rules: &FieldRules,
+    tickets: &[Vec<u32>],
+) -> std::result::Result<HashMap<String, usize>, AmbiguousFields> {
+    if tickets.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut potential_positions: HashMap<&String, HashSet<usize>> = HashMap::new();
+
+    for pos in 0..tickets[0].len() {
+        for (field, rules) in field_rules {
+            if tickets
+                .iter()
+                .map(|t| t[pos])
+                .all(|tn| rules.iter().any(|r| r.contains(&tn)))
+            {
+                potential_positions.entry(field).or_default().insert(pos);
+            }
+        }
+    }
+
+    let mut determined_positions = HashSet::new();
+    let mut field_positions = HashMap::new();
+
+    while !potential_positions.is_empty() {
+        let matched_fields = potential_positions
+            .iter()
+            .filter_map(|(&field, potential_positions)| {
+                let pos_diff = potential_positions
+                    .difference(&determined_positions)
+                    .collect::<Vec<_>>();
+
+                if pos_diff.len() == 1 {
+                    let pos = *pos_diff[0];
+                    field_positions.insert(String::from(field), pos);
+                    determined_positions.insert(pos);
+                    Some(field)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if matched_fields.is_empty() {
+            let mut fields = potential_positions
+                .keys()
+                .map(|f| f.as_str())
+                .collect::<Vec<_>>();
+            fields.sort_unstable();
+
+            return Err(AmbiguousFields(fields.join(", ")));
+        }
+
+        for field in matched_fields {
+            potential_positions.remove(field);
+        }
+    }
+
+    Ok(field_positions)
+}
+
+#[derive(ThisError, Debug, Clone, PartialEq)]
+#[error("The positions of the fields {0} can't be determined")]
+pub struct AmbiguousFields(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::{determine_field_positions, determine_ticket_scanning_error_rate, parse};
+
+    #[test]
+    fn test_determine_ticket_scanning_error_rate() {
+        let data = r#"
+            class: 1-3 or 5-7
+            row: 6-11 or 33-44
+            seat: 13-40 or 45-50
+
+            your ticket:
+            7,1,14
+
+            nearby tickets:
+            7,3,47
+            40,4,50
+            55,2,20
+            38,6,12
+        "#;
+
+        let (field_rules, _my_ticket, nearby_tickets) = parse(data.as_bytes()).unwrap();
+        let error_rate = determine_ticket_scanning_error_rate(&field_rules, &nearby_tickets);
+
+        assert_eq!(71, error_rate);
+    }
+
+    #[test]
+    fn test_determine_field_positions() {
+        let data = r#"
+            class: 0-1 or 4-19
+            row: 0-5 or 8-19
+            seat: 0-13 or 16-19
+
+            your ticket:
+            11,12,13
+
+            nearby tickets:
+            3,9,18
+            15,1,5
+            5,14,9
+        "#;
+
+        let (field_rules, _my_ticket, nearby_tickets) = parse(data.as_bytes()).unwrap();
+        let field_positions = determine_field_positions(&field_rules, &nearby_tickets).unwrap();
+
+        assert_eq!(Some(&0), field_positions.get("row"));
+        assert_eq!(Some(&1), field_positions.get("class"));
+        assert_eq!(Some(&2), field_positions.get("seat"));
+    }
+
+    #[test]
+    fn test_invalid_notes() {
+        let data = r#"
+            class: 0-1 or 4-19
+            row: 0-1 or 4-19
+
+            your ticket:
+            11,12
+
+            nearby tickets:
+            5,9
+        "#;
+
+        let (field_rules, _my_ticket, nearby_tickets) = parse(data.as_bytes()).unwrap();
+        let error = determine_field_positions(&field_rules, &nearby_tickets).unwrap_err();
+        assert_eq!("class, row", error.0);
+
+        let error = parse("row: 0-1 or 4-19\n\nnearby tickets:\n3\n".as_bytes()).unwrap_err();
+        assert_eq!(4, error.line);
+
+        let error =
+            parse("row: 0-1 or 4-19\nyour ticket:\nnearby tickets:\n".as_bytes()).unwrap_err();
+        assert_eq!(2, error.line);
+    }
+}
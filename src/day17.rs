@@ -0,0 +1,308 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::BufRead;
+use std::ops::{Deref, DerefMut};
+
+use crate::grid::Grid as Slice;
+use crate::parse::ParseResult;
+use crate::solver::{Result, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut grid = Grid::new(Dimensions::Three);
+        parse_grid_slice(input.as_bytes(), &mut grid)?;
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Self::Output1> {
+        let mut grid = grid.clone();
+        simulate_cycles(&mut grid, 6);
+
+        Ok(grid.active_count())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Self::Output2> {
+        let mut grid = grid.clone();
+        grid.dimensions = Dimensions::Four;
+        simulate_cycles(&mut grid, 6);
+
+        Ok(grid.active_count())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Dimensions {
+    Three,
+    Four,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub dimensions: Dimensions,
+    pub cubes: HashMap<Position, bool>,
+}
+
+impl Grid {
+    pub fn new(dimensions: Dimensions) -> Self {
+        Self {
+            dimensions,
+            cubes: HashMap::new(),
+        }
+    }
+
+    pub fn active_bounds(&self) -> (Position, Position) {
+        let active_positions = self.active_positions_iter().collect::<Vec<_>>();
+        let min = Position {
+            x: active_positions
+                .iter()
+                .map(|p| p.x)
+                .min()
+                .unwrap_or_default(),
+            y: active_positions
+                .iter()
+                .map(|p| p.y)
+                .min()
+                .unwrap_or_default(),
+            z: active_positions
+                .iter()
+                .map(|p| p.z)
+                .min()
+                .unwrap_or_default(),
+            w: active_positions
+                .iter()
+                .map(|p| p.w)
+                .min()
+                .unwrap_or_default(),
+        };
+
+        let max = Position {
+            x: active_positions
+                .iter()
+                .map(|p| p.x)
+                .max()
+                .unwrap_or_default(),
+            y: active_positions
+                .iter()
+                .map(|p| p.y)
+                .max()
+                .unwrap_or_default(),
+            z: active_positions
+                .iter()
+                .map(|p| p.z)
+                .max()
+                .unwrap_or_default(),
+            w: active_positions
+                .iter()
+                .map(|p| p.w)
+                .max()
+                .unwrap_or_default(),
+        };
+
+        (min, max)
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.active_positions_iter().count()
+    }
+
+    pub fn active_positions_iter(&self) -> impl Iterator<Item = &Position> {
+        self.iter().filter(|(_k, &v)| v).map(|(k, _v)| k)
+    }
+}
+
+impl Deref for Grid {
+    type Target = HashMap<Position, bool>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cubes
+    }
+}
+
+impl DerefMut for Grid {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cubes
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.active_bounds();
+
+        for z in min.z..=max.z {
+            for w in min.w..=max.w {
+                writeln!(f, "z={} w={}", z, w)?;
+
+                for y in min.y..=max.y {
+                    write!(f, "y: {} ", y)?;
+                    for x in min.x..=max.x {
+                        write!(
+                            f,
+                            "{}",
+                            if *self.get(&Position { x, y, z, w }).unwrap_or(&false) {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        )?;
+                    }
+
+                    writeln!(f)?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Default, Copy, Clone, Ord, PartialOrd)]
+pub struct Position {
+    pub z: i32,
+    pub y: i32,
+    pub x: i32,
+    pub w: i32,
+}
+
+impl Position {
+    pub fn neighbours(&self, dimensions: Dimensions) -> Vec<Position> {
+        let w_offsets = match dimensions {
+            Dimensions::Three => 0..=0,
+            Dimensions::Four => -1..=1,
+        };
+
+        let mut n = Vec::with_capacity(80);
+
+        for ox in -1..=1 {
+            for oy in -1..=1 {
+                for oz in -1..=1 {
+                    for ow in w_offsets.clone() {
+                        if ox == 0 && oy == 0 && oz == 0 && ow == 0 {
+                            continue;
+                        }
+
+                        n.push(Self {
+                            x: self.x + ox,
+                            y: self.y + oy,
+                            z: self.z + oz,
+                            w: self.w + ow,
+                        });
+                    }
+                }
+            }
+        }
+
+        n
+    }
+}
+
+pub fn parse_grid_slice(reader: impl BufRead, target: &mut Grid) -> ParseResult<()> {
+    let slice = Slice::parse(reader, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("Expected either an active (#) or an inactive (.) cube"),
+    })?;
+
+    for (pos, &active) in slice.iter() {
+        target.insert(
+            Position {
+                x: pos.x,
+                y: pos.y,
+                z: 0,
+                w: 0,
+            },
+            active,
+        );
+    }
+
+    Ok(())
+}
+
+pub fn simulate_cycles(grid: &mut Grid, cycles: usize) {
+    for _ in 0..cycles {
+        simulate_cycle(grid);
+    }
+}
+
+pub fn simulate_cycle(grid: &mut Grid) {
+    let mut deactivate = HashSet::new();
+    let mut activate = HashSet::new();
+
+    fn active_neighbours(pos: &Position, grid: &Grid) -> usize {
+        pos.neighbours(grid.dimensions)
+            .iter()
+            .filter_map(|np| grid.get(np))
+            .filter(|&&ns| ns)
+            .count()
+    }
+
+    let mut check_pos = |pos: &Position, grid: &Grid| {
+        let state = *grid.get(pos).unwrap_or(&false);
+        let active_nb = active_neighbours(pos, grid);
+
+        if state {
+            if active_nb != 2 && active_nb != 3 {
+                deactivate.insert(*pos);
+            }
+        } else if active_nb == 3 {
+            activate.insert(*pos);
+        }
+    };
+
+    let mut positions_to_check = HashSet::new();
+    for pos in grid.active_positions_iter() {
+        check_pos(pos, grid);
+        positions_to_check.extend(pos.neighbours(grid.dimensions));
+    }
+
+    for pos in positions_to_check {
+        check_pos(&pos, grid);
+    }
+
+    for pos in deactivate {
+        grid.insert(pos, false);
+    }
+
+    for pos in activate {
+        grid.insert(pos, true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_grid_slice, simulate_cycles, Dimensions, Grid};
+
+    const EXAMPLE: &str = r#"
+        .#.
+        ..#
+        ###
+    "#;
+
+    #[test]
+    fn test_simulate_cycles() {
+        let mut grid = Grid::new(Dimensions::Three);
+        parse_grid_slice(EXAMPLE.trim().as_bytes(), &mut grid).unwrap();
+
+        simulate_cycles(&mut grid, 6);
+
+        assert_eq!(112, grid.active_count());
+    }
+
+    #[test]
+    fn test_simulate_hyper_cycles() {
+        let mut grid = Grid::new(Dimensions::Four);
+        parse_grid_slice(EXAMPLE.trim().as_bytes(), &mut grid).unwrap();
+
+        simulate_cycles(&mut grid, 6);
+
+        assert_eq!(848, grid.active_count());
+    }
+}
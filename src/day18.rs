@@ -0,0 +1,539 @@
+use std::convert::TryFrom;
+use std::io::BufRead;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day18;
+
+/// Adapted http://www.craftinginterpreters.com/
+impl Solution for Day18 {
+    type Input = Vec<Vec<Lexeme>>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Output1> {
+        Ok(evaluate_lines(lines, Precedence::Equal)?)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Output2> {
+        Ok(evaluate_lines(lines, Precedence::AdditionFirst)?)
+    }
+}
+
+/// Scans every non-empty line and checks that it forms a valid expression
+pub fn parse(reader: impl BufRead) -> ParseResult<Vec<Vec<Lexeme>>> {
+    parse::lines(reader)
+        .filter(|line| !matches!(line, Ok((_, l)) if l.trim().is_empty()))
+        .map(|line| {
+            let (idx, line) = line?;
+            let to_parse_error = |e: SyntaxError| {
+                let text = line.chars().skip(e.offset).take(e.len).collect::<String>();
+
+                ParseError::at(idx, e.offset, text, e)
+            };
+
+            let lexemes = Scanner::new(&line).scan().map_err(to_parse_error)?;
+            Parser::new(lexemes.clone(), Precedence::default())
+                .parse()
+                .map_err(to_parse_error)?;
+
+            Ok(lexemes)
+        })
+        .collect()
+}
+
+pub fn evaluate_lines(
+    lines: &[Vec<Lexeme>],
+    precedence: Precedence,
+) -> std::result::Result<i64, EvalError> {
+    lines.iter().try_fold(0i64, |sum, lexemes| {
+        let expr = Parser::new(lexemes.clone(), precedence).parse()?;
+
+        sum.checked_add(evaluate_expression(&expr)?)
+            .ok_or(EvalError::Overflow)
+    })
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Token {
+    LeftParen,
+    RightParen,
+    Minus,
+    Plus,
+    Slash,
+    Star,
+    Number(u64),
+    EndOfFile,
+}
+
+/// A token together with its char offset and length in the scanned input
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lexeme {
+    pub token: Token,
+    pub offset: usize,
+    pub len: usize,
+}
+
+#[derive(Debug)]
+pub enum Expr {
+    Binary {
+        left: Box<Self>,
+        operator: Token,
+        right: Box<Self>,
+    },
+    Unary {
+        operator: Token,
+        right: Box<Self>,
+    },
+    Grouping(Box<Self>),
+    Literal(Token),
+}
+
+#[derive(Default)]
+pub struct Scanner {
+    input: Vec<char>,
+    start: usize,
+    current: usize,
+    lexemes: Vec<Lexeme>,
+}
+
+impl Scanner {
+    pub fn new(input: &str) -> Self {
+        Self {
+            input: input.chars().collect::<Vec<_>>(),
+            ..Self::default()
+        }
+    }
+
+    pub fn scan(mut self) -> std::result::Result<Vec<Lexeme>, SyntaxError> {
+        while !self.is_at_end() {
+            self.start = self.current;
+            self.scan_token()?;
+        }
+
+        self.start = self.current;
+        self.add_token(Token::EndOfFile);
+
+        Ok(self.lexemes)
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.current >= self.input.len()
+    }
+
+    fn scan_token(&mut self) -> std::result::Result<(), SyntaxError> {
+        use Token::*;
+
+        let c = self.advance();
+
+        match c {
+            '(' => self.add_token(LeftParen),
+            ')' => self.add_token(RightParen),
+            '-' => self.add_token(Minus),
+            '+' => self.add_token(Plus),
+            '*' => self.add_token(Star),
+            '/' => self.add_token(Slash),
+            c if c.is_whitespace() => {}
+            c if c.is_ascii_digit() => self.number()?,
+            _ => return Err(self.error("Unexpected character")),
+        }
+
+        Ok(())
+    }
+
+    fn advance(&mut self) -> char {
+        self.current += 1;
+        self.input[self.current - 1]
+    }
+
+    fn peek(&self) -> char {
+        if self.is_at_end() {
+            '\0'
+        } else {
+            self.input[self.current]
+        }
+    }
+
+    fn add_token(&mut self, token: Token) {
+        self.lexemes.push(Lexeme {
+            token,
+            offset: self.start,
+            len: self.current - self.start,
+        });
+    }
+
+    fn number(&mut self) -> std::result::Result<(), SyntaxError> {
+        while self.peek().is_ascii_digit() {
+            self.advance();
+        }
+
+        let s = self.input[self.start..self.current]
+            .iter()
+            .collect::<String>();
+
+        let number = s
+            .parse::<u64>()
+            .map_err(|_| self.error("Number is too large"))?;
+
+        self.add_token(Token::Number(number));
+
+        Ok(())
+    }
+
+    fn error(&self, message: &'static str) -> SyntaxError {
+        SyntaxError {
+            offset: self.start,
+            len: self.current - self.start,
+            message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Precedence {
+    /// All binary operators bind equally and are evaluated left to right
+    #[default]
+    Equal,
+    /// Addition and subtraction bind tighter than multiplication and division
+    AdditionFirst,
+}
+
+#[derive(Default)]
+pub struct Parser {
+    lexemes: Vec<Lexeme>,
+    current: usize,
+    precedence: Precedence,
+}
+
+impl Parser {
+    pub fn new(lexemes: Vec<Lexeme>, precedence: Precedence) -> Self {
+        Self {
+            lexemes,
+            precedence,
+            ..Self::default()
+        }
+    }
+
+    pub fn parse(mut self) -> std::result::Result<Expr, SyntaxError> {
+        let expr = self.expression()?;
+
+        if !self.is_at_end() {
+            return Err(self.error("Expect end of expression"));
+        }
+
+        Ok(expr)
+    }
+
+    fn match_token(&mut self, check: impl Fn(&Token) -> bool) -> bool {
+        if self.check_token(check) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn check_token(&self, check: impl Fn(&Token) -> bool) -> bool {
+        if self.is_at_end() {
+            false
+        } else {
+            check(self.peek())
+        }
+    }
+
+    fn consume_token(
+        &mut self,
+        check: impl Fn(&Token) -> bool,
+        error_msg: &'static str,
+    ) -> std::result::Result<(), SyntaxError> {
+        if self.check_token(check) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(error_msg))
+        }
+    }
+
+    fn error(&self, message: &'static str) -> SyntaxError {
+        let lexeme = &self.lexemes[self.current];
+
+        SyntaxError {
+            offset: lexeme.offset,
+            len: lexeme.len,
+            message,
+        }
+    }
+
+    fn advance(&mut self) -> &Token {
+        if !self.is_at_end() {
+            self.current += 1;
+        }
+
+        self.previous()
+    }
+
+    fn previous(&self) -> &Token {
+        &self.lexemes[self.current - 1].token
+    }
+
+    fn peek(&self) -> &Token {
+        &self.lexemes[self.current].token
+    }
+
+    fn is_at_end(&self) -> bool {
+        matches!(self.peek(), Token::EndOfFile)
+    }
+
+    fn expression(&mut self) -> std::result::Result<Expr, SyntaxError> {
+        match self.precedence {
+            Precedence::Equal => self.factor_term(),
+            Precedence::AdditionFirst => self.factor(),
+        }
+    }
+
+    fn factor_term(&mut self) -> std::result::Result<Expr, SyntaxError> {
+        self.binary(
+            |t| matches!(t, Token::Slash | Token::Star | Token::Minus | Token::Plus),
+            Self::unary,
+        )
+    }
+
+    fn factor(&mut self) -> std::result::Result<Expr, SyntaxError> {
+        self.binary(|t| matches!(t, Token::Slash | Token::Star), Self::term)
+    }
+
+    fn term(&mut self) -> std::result::Result<Expr, SyntaxError> {
+        self.binary(|t| matches!(t, Token::Minus | Token::Plus), Self::unary)
+    }
+
+    fn binary(
+        &mut self,
+        is_operator: impl Fn(&Token) -> bool,
+        operand: impl Fn(&mut Self) -> std::result::Result<Expr, SyntaxError>,
+    ) -> std::result::Result<Expr, SyntaxError> {
+        let mut expr = operand(self)?;
+
+        while self.match_token(&is_operator) {
+            let operator = self.previous().clone();
+            let right = operand(self)?;
+
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }
+        }
+
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> std::result::Result<Expr, SyntaxError> {
+        if self.match_token(|t| matches!(t, Token::Minus)) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+
+            Ok(Expr::Unary {
+                operator,
+                right: Box::new(right),
+            })
+        } else {
+            self.primary()
+        }
+    }
+
+    fn primary(&mut self) -> std::result::Result<Expr, SyntaxError> {
+        if self.match_token(|t| matches!(t, Token::Number(_))) {
+            Ok(Expr::Literal(self.previous().clone()))
+        } else if self.match_token(|t| matches!(t, Token::LeftParen)) {
+            let expr = self.expression()?;
+            self.consume_token(
+                |t| matches!(t, Token::RightParen),
+                "Expect ')' after expression",
+            )?;
+            Ok(Expr::Grouping(Box::new(expr)))
+        } else {
+            Err(self.error("Expect expression"))
+        }
+    }
+}
+
+#[derive(ThisError, Debug)]
+#[error("{message}")]
+pub struct SyntaxError {
+    pub offset: usize,
+    pub len: usize,
+    pub message: &'static str,
+}
+
+pub fn evaluate_expression(expr: &Expr) -> std::result::Result<i64, EvalError> {
+    Ok(match expr {
+        Expr::Literal(Token::Number(n)) => i64::try_from(*n).map_err(|_| EvalError::Overflow)?,
+        Expr::Grouping(expr) => evaluate_expression(expr.as_ref())?,
+        Expr::Unary { operator, right } => {
+            let right = evaluate_expression(right.as_ref())?;
+
+            match operator {
+                Token::Minus => right.checked_neg().ok_or(EvalError::Overflow)?,
+                _ => unreachable!(),
+            }
+        }
+        Expr::Binary {
+            left,
+            operator,
+            right,
+        } => {
+            let left = evaluate_expression(left.as_ref())?;
+            let right = evaluate_expression(right.as_ref())?;
+
+            match operator {
+                Token::Slash if right == 0 => return Err(EvalError::DivisionByZero),
+                Token::Minus => left.checked_sub(right),
+                Token::Plus => left.checked_add(right),
+                Token::Star => left.checked_mul(right),
+                Token::Slash => left.checked_div(right),
+                _ => unreachable!(),
+            }
+            .ok_or(EvalError::Overflow)?
+        }
+        _ => unreachable!(),
+    })
+}
+
+#[derive(ThisError, Debug)]
+pub enum EvalError {
+    #[error(transparent)]
+    Syntax(#[from] SyntaxError),
+    #[error("Division by zero")]
+    DivisionByZero,
+    #[error("The result doesn't fit into 64 bits")]
+    Overflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        evaluate_expression, evaluate_lines, parse, EvalError, Parser, Precedence, Scanner, Token,
+    };
+
+    #[test]
+    fn test_scanner() {
+        let input = "2 * 3 + (4 * 5)";
+        let scanner = Scanner::new(input);
+        let tokens = scanner
+            .scan()
+            .unwrap()
+            .into_iter()
+            .map(|l| l.token)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                Token::Number(2),
+                Token::Star,
+                Token::Number(3),
+                Token::Plus,
+                Token::LeftParen,
+                Token::Number(4),
+                Token::Star,
+                Token::Number(5),
+                Token::RightParen,
+                Token::EndOfFile
+            ],
+            tokens
+        );
+    }
+
+    #[test]
+    fn test_eval() {
+        let data = &[
+            ("2 * 3 + (4 * 5)", 26),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240),
+            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632),
+        ];
+
+        for (input, result) in data {
+            assert_eq!(
+                *result,
+                evaluate_expression(
+                    &Parser::new(Scanner::new(input).scan().unwrap(), Precedence::Equal)
+                        .parse()
+                        .unwrap()
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_eval_addition_first() {
+        let data = &[
+            ("1 + (2 * 3) + (4 * (5 + 6))", 51),
+            ("2 * 3 + (4 * 5)", 46),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 1445),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 669060),
+            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 23340),
+        ];
+
+        for (input, result) in data {
+            assert_eq!(
+                *result,
+                evaluate_expression(
+                    &Parser::new(
+                        Scanner::new(input).scan().unwrap(),
+                        Precedence::AdditionFirst
+                    )
+                    .parse()
+                    .unwrap()
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let data = &[
+            (
+                "1 + 2\n3 * (4 + 5",
+                (2, 11),
+                "",
+                "Expect ')' after expression",
+            ),
+            ("2 * 3 % 4", (1, 7), "%", "Unexpected character"),
+            ("2 * + 4", (1, 5), "+", "Expect expression"),
+            ("(2 * 3) 4", (1, 9), "4", "Expect end of expression"),
+        ];
+
+        for (input, location, text, message) in data {
+            let error = parse(input.as_bytes()).unwrap_err();
+
+            assert_eq!(*location, (error.line, error.column));
+            assert_eq!(*text, error.text);
+            assert_eq!(*message, error.message);
+        }
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let data = &[
+            ("1 + 2 / (3 - 3)", EvalError::DivisionByZero),
+            ("99999999999 * 99999999999", EvalError::Overflow),
+            ("-(9223372036854775807 + 1)", EvalError::Overflow),
+            ("18446744073709551615", EvalError::Overflow),
+        ];
+
+        for (input, expected) in data {
+            let lines = parse(input.as_bytes()).unwrap();
+            let error = evaluate_lines(&lines, Precedence::Equal).unwrap_err();
+
+            assert_eq!(expected.to_string(), error.to_string());
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use itertools::Itertools;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::BufRead;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Rules, Vec<String>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1((rules, messages): &Self::Input) -> Result<Self::Output1> {
+        let allowed_messages = expand_rule(rules, 0)?;
+
+        Ok(messages
+            .iter()
+            .filter(|m| allowed_messages.contains(m.as_str()))
+            .count())
+    }
+
+    fn part2((rules, messages): &Self::Input) -> Result<Self::Output2> {
+        if !rules.contains_key(&0) {
+            return Err(RuleError::Undefined(0).into());
+        }
+
+        let mut rules = rules.clone();
+        insert_looping_rules(&mut rules);
+
+        Ok(messages
+            .iter()
+            .filter(|m| is_valid_message(&rules, 0, m.as_ref()))
+            .count())
+    }
+}
+
+pub type Rules = HashMap<usize, Vec<Vec<RulePart>>>;
+
+#[derive(Debug, Clone)]
+pub enum RulePart {
+    Literal(char),
+    Idx(usize),
+}
+
+/// Replaces the rules 8 and 11 with the looping rules of part 2
+pub fn insert_looping_rules(rules: &mut Rules) {
+    use RulePart::Idx;
+
+    rules.insert(8, vec![vec![Idx(42)], vec![Idx(42), Idx(8)]]);
+    rules.insert(
+        11,
+        vec![vec![Idx(42), Idx(31)], vec![Idx(42), Idx(11), Idx(31)]],
+    );
+}
+
+/// Reads the rules and the messages, every rule referenced by another rule has to be defined
+pub fn parse(input: impl BufRead) -> ParseResult<(Rules, Vec<String>)> {
+    let mut parse_rules = true;
+    let (mut rules, mut messages) = (HashMap::new(), Vec::new());
+    let mut references = BTreeMap::new();
+
+    for line in parse::lines(input) {
+        let (idx, line) = line?;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            if !rules.is_empty() {
+                parse_rules = false;
+            }
+
+            continue;
+        }
+
+        if !parse_rules {
+            messages.push(String::from(trimmed));
+            continue;
+        }
+
+        let mut line_parts = trimmed.splitn(2, ':');
+        let rule_idx = line_parts.next().unwrap_or_default();
+        let rule_idx = rule_idx
+            .parse::<usize>()
+            .map_err(|e| ParseError::new(idx, &line, rule_idx, e))?;
+        let rule_parts = line_parts
+            .next()
+            .ok_or_else(|| {
+                ParseError::new(
+                    idx,
+                    &line,
+                    trimmed,
+                    "Expected a rule like \"<idx>: <rule>\"",
+                )
+            })?
+            .split('|');
+
+        let rule = rule_parts
+            .map(|rule_part| {
+                rule_part
+                    .split_whitespace()
+                    .map(|s| {
+                        if s.len() >= 3 && s.starts_with('"') && s.ends_with('"') {
+                            let literal = s.trim_matches('"');
+                            let mut chars = literal.chars();
+
+                            match (chars.next(), chars.next()) {
+                                (Some(c), None) => Ok(RulePart::Literal(c)),
+                                _ => Err(ParseError::new(
+                                    idx,
+                                    &line,
+                                    s,
+                                    "Expected a single character literal",
+                                )),
+                            }
+                        } else {
+                            let rule_idx = s
+                                .parse::<usize>()
+                                .map_err(|e| ParseError::new(idx, &line, s, e))?;
+
+                            references.entry(rule_idx).or_insert_with(|| {
+                                ParseError::new(idx, &line, s, "Undefined rule")
+                            });
+
+                            Ok(RulePart::Idx(rule_idx))
+                        }
+                    })
+                    .collect::<ParseResult<Vec<_>>>()
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        rules.insert(rule_idx, rule);
+    }
+
+    let undefined = references
+        .into_iter()
+        .filter(|(rule_idx, _)| !rules.contains_key(rule_idx))
+        .map(|(_, error)| error)
+        .min_by_key(|error| (error.line, error.column));
+
+    match undefined {
+        Some(error) => Err(error),
+        None => Ok((rules, messages)),
+    }
+}
+
+/// All messages matched by the given rule, fails for undefined rules and rules referring to
+/// themselves
+pub fn expand_rule(rules: &Rules, idx: usize) -> std::result::Result<HashSet<String>, RuleError> {
+    let mut cache = HashMap::new();
+    let mut expanding = HashSet::new();
+
+    fn expand(
+        rules: &Rules,
+        idx: usize,
+        cache: &mut HashMap<usize, Vec<String>>,
+        expanding: &mut HashSet<usize>,
+    ) -> std::result::Result<Vec<String>, RuleError> {
+        if let Some(v) = cache.get(&idx) {
+            return Ok(v.clone());
+        }
+
+        let rule = rules.get(&idx).ok_or(RuleError::Undefined(idx))?;
+        if !expanding.insert(idx) {
+            return Err(RuleError::Cycle(idx));
+        }
+
+        let mut results = Vec::new();
+        for sub_rule in rule {
+            sub_rule
+                .iter()
+                .map(|rule_part| match rule_part {
+                    RulePart::Literal(c) => Ok(vec![String::from(*c)]),
+                    RulePart::Idx(idx) => expand(rules, *idx, cache, expanding),
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?
+                .into_iter()
+                .multi_cartesian_product()
+                .map(|v| v.iter().map(|s| s.as_str()).join(""))
+                .for_each(|v| results.push(v));
+        }
+
+        expanding.remove(&idx);
+        cache.insert(idx, results.clone());
+
+        Ok(results)
+    }
+
+    Ok(expand(rules, idx, &mut cache, &mut expanding)?
+        .into_iter()
+        .collect())
+}
+
+pub fn is_valid_message(rules: &Rules, idx: usize, msg: &str) -> bool {
+    /// All lengths of the message prefixes that are matched by the given rule
+    fn match_lengths(rules: &Rules, idx: usize, msg: &str) -> Vec<usize> {
+        let sub_rules = match rules.get(&idx) {
+            Some(sub_rules) => sub_rules,
+            None => return Vec::new(),
+        };
+
+        sub_rules
+            .iter()
+            .flat_map(|sub_rule| {
+                sub_rule.iter().fold(vec![0], |offsets, rule_part| {
+                    offsets
+                        .into_iter()
+                        .flat_map(|offset| {
+                            let rest = &msg[offset..];
+
+                            match rule_part {
+                                RulePart::Literal(c) if rest.starts_with(*c) => {
+                                    vec![offset + c.len_utf8()]
+                                }
+                                RulePart::Literal(_) => Vec::new(),
+                                // every rule consumes at least one char, stopping at the end of
+                                // the message keeps looping rules from recursing endlessly
+                                RulePart::Idx(_) if rest.is_empty() => Vec::new(),
+                                RulePart::Idx(rule_idx) => match_lengths(rules, *rule_idx, rest)
+                                    .into_iter()
+                                    .map(|len| offset + len)
+                                    .collect(),
+                            }
+                        })
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    match_lengths(rules, idx, msg).contains(&msg.len())
+}
+
+#[derive(ThisError, Debug, PartialEq)]
+pub enum RuleError {
+    #[error("Rule {0} is not defined")]
+    Undefined(usize),
+    #[error("Rule {0} refers to itself")]
+    Cycle(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_rule, insert_looping_rules, is_valid_message, parse, RuleError};
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = r#"
+        0: 4 1 5
+        1: 2 3 | 3 2
+        2: 4 4 | 5 5
+        3: 4 5 | 5 4
+        4: "a"
+        5: "b"
+
+        ababbb
+        bababa
+        abbbab
+        aaabbb
+        aaaabbb
+    "#;
+
+    #[test]
+    fn test_matching_messages() {
+        let (rules, messages) = parse(EXAMPLE.trim().as_bytes()).unwrap();
+        let allowed_messages: HashSet<String> = expand_rule(&rules, 0).unwrap();
+
+        let valid_messages = messages
+            .iter()
+            .filter(|m| allowed_messages.contains(*m))
+            .count();
+
+        assert_eq!(2, valid_messages);
+    }
+
+    #[test]
+    fn test_rule_expansion() {
+        let (rules, _messages) = parse(EXAMPLE.trim().as_bytes()).unwrap();
+        let allowed_messages: HashSet<String> = expand_rule(&rules, 0).unwrap();
+        let mut result = allowed_messages.into_iter().collect::<Vec<_>>();
+        result.sort();
+
+        let mut expected = vec![
+            "aaaabb", "aaabab", "abbabb", "abbbab", "aabaab", "aabbbb", "abaaab", "ababbb",
+        ];
+
+        expected.sort_unstable();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_matching_messages_with_loops() {
+        let input = r#"
+            42: 9 14 | 10 1
+            9: 14 27 | 1 26
+            10: 23 14 | 28 1
+            1: "a"
+            11: 42 31
+            5: 1 14 | 15 1
+            19: 14 1 | 14 14
+            12: 24 14 | 19 1
+            16: 15 1 | 14 14
+            31: 14 17 | 1 13
+            6: 14 14 | 1 14
+            2: 1 24 | 14 4
+            0: 8 11
+            13: 14 3 | 1 12
+            15: 1 | 14
+            17: 14 2 | 1 7
+            23: 25 1 | 22 14
+            28: 16 1
+            4: 1 1
+            20: 14 14 | 1 15
+            3: 5 14 | 16 1
+            27: 1 6 | 14 18
+            14: "b"
+            21: 14 1 | 1 14
+            25: 1 1 | 1 14
+            22: 14 14
+            8: 42
+            26: 14 22 | 1 20
+            18: 15 15
+            7: 14 5 | 1 21
+            24: 14 1
+
+            abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+            bbabbbbaabaabba
+            babbbbaabbbbbabbbbbbaabaaabaaa
+            aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+            bbbbbbbaaaabbbbaaabbabaaa
+            bbbababbbbaaaaaaaabbababaaababaabab
+            ababaaaaaabaaab
+            ababaaaaabbbaba
+            baabbaaaabbaaaababbaababb
+            abbbbabbbbaaaababbbbbbaaaababb
+            aaaaabbaabaaaaababaa
+            aaaabbaaaabbaaa
+            aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+            babaaabbbaaabaababbaabababaaab
+            aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+        "#;
+
+        let (mut rules, _messages) = parse(input.trim().as_bytes()).unwrap();
+        insert_looping_rules(&mut rules);
+
+        assert!(!is_valid_message(&rules, 0, "aaaabbaaaabbaaa"));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let error = parse("0: 1 2\n1: \"a\"\n2: 1 3\n3: 4".as_bytes()).unwrap_err();
+        assert_eq!((4, 4), (error.line, error.column));
+        assert_eq!("4", error.text);
+
+        let (rules, _messages) = parse("0: 1 | 2\n1: \"a\"\n2: 1 0".as_bytes()).unwrap();
+        assert_eq!(Err(RuleError::Cycle(0)), expand_rule(&rules, 0));
+        assert_eq!(Err(RuleError::Undefined(5)), expand_rule(&rules, 5));
+    }
+}
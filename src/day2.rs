@@ -0,0 +1,1049 @@
+use itertools::Itertools;
+use regex::Regex;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+use policy::{ClassRule, OccurrencePolicy, PasswordPolicy, PositionPolicy};
+
+#[derive(Debug)]
+pub struct LineData {
+    /// Line number within the input, starting at 1
+    pub line: usize,
+    pub occurrences: RangeInclusive<u32>,
+    /// The characters the occurrence or position rule applies to, any of them counts
+    pub characters: Vec<char>,
+    /// Optional rules of the line, checked by the `length`, `class` and `regex` policies
+    pub length: Option<RangeInclusive<usize>>,
+    pub classes: Vec<ClassRule>,
+    pub regex: Option<Regex>,
+    pub password: String,
+}
+
+impl fmt::Display for LineData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}",
+            self.occurrences.start(),
+            self.occurrences.end(),
+            self.characters.iter().join(","),
+        )?;
+
+        if let Some(length) = &self.length {
+            write!(f, " len={}-{}", length.start(), length.end())?;
+        }
+        for class in self.classes.iter() {
+            write!(f, " class={}", class)?;
+        }
+        if let Some(regex) = &self.regex {
+            write!(f, " re=\"{}\"", regex.as_str().replace('"', "\\\""))?;
+        }
+
+        write!(f, ": {}", self.password)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<LineData>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Output1> {
+        Ok(count_valid(lines, &OccurrencePolicy))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Output2> {
+        Ok(count_valid(lines, &PositionPolicy))
+    }
+}
+
+impl LineData {
+    pub fn is_required(&self, c: char) -> bool {
+        self.characters.contains(&c)
+    }
+}
+
+pub fn parse(reader: impl BufRead) -> ParseResult<Vec<LineData>> {
+    match parse_all(reader) {
+        (_, errors) if !errors.is_empty() => Err(errors.into_iter().next().unwrap()),
+        (lines, _) => Ok(lines),
+    }
+}
+
+/// Parses every line, lines that can't be parsed are returned as errors instead of stopping
+/// at the first one
+pub fn parse_all(reader: impl BufRead) -> (Vec<LineData>, Vec<ParseError>) {
+    let mut lines = Vec::new();
+    let mut errors = Vec::new();
+
+    for line in parse::lines(reader) {
+        let parsed = line.and_then(|(idx, line)| {
+            if line.trim().is_empty() {
+                Ok(None)
+            } else {
+                parse_line(idx, &line).map(Some)
+            }
+        });
+
+        match parsed {
+            Ok(Some(line)) => lines.push(line),
+            Ok(None) => {}
+            Err(e) => errors.push(e),
+        }
+    }
+
+    (lines, errors)
+}
+
+/// Parses lines like `1-3 a: abcde` or `1-3 a,b: abcde`
+///
+/// Optional rules may follow the characters: `len=8-64` limits the password length,
+/// `class=digit:1` or `class=upper:1-2` requires a number of characters of a class and can be
+/// repeated, `re="^[a-z]+$"` requires the password to match a regular expression. Within a
+/// quoted pattern `\"` escapes a quote.
+fn parse_line(idx: usize, line: &str) -> ParseResult<LineData> {
+    let trimmed = line.trim();
+    let error = |text: &str, message: &str| ParseError::new(idx, line, text, message);
+    let syntax_error = || error(trimmed, "Expected a password policy like \"1-3 a: abcde\"");
+
+    let (range, mut rest) =
+        trimmed.split_at(trimmed.find(char::is_whitespace).ok_or_else(syntax_error)?);
+    let (min, max) = range.split_once('-').ok_or_else(syntax_error)?;
+
+    let occurrence = |s: &str| s.parse::<u32>().map_err(|e| error(s, &e.to_string()));
+    let occurrences = occurrence(min)?..=occurrence(max)?;
+
+    let mut characters = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        let end = rest
+            .find(|c: char| c == ',' || c == ':' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let s = &rest[..end];
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => characters.push(c),
+            _ => return Err(error(s, "Expected a single character")),
+        }
+
+        rest = rest[end..].trim_start();
+        match rest.strip_prefix(',') {
+            Some(remainder) => rest = remainder,
+            None => break,
+        }
+    }
+
+    let mut length = None;
+    let mut classes = Vec::new();
+    let mut regex = None;
+
+    let password = loop {
+        rest = rest.trim_start();
+        if let Some(password) = rest.strip_prefix(':') {
+            break password.trim();
+        }
+
+        let key_end = rest
+            .find(|c: char| c == '=' || c == ':' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        let after = match rest[key_end..].strip_prefix('=') {
+            Some(after) => after,
+            None => {
+                return Err(error(
+                    key,
+                    "Expected a rule like len=8-64 or a : and the password",
+                ))
+            }
+        };
+        let (raw, value, remainder) =
+            rule_value(after).ok_or_else(|| error(after, "Unterminated string"))?;
+        rest = remainder;
+
+        match key {
+            "len" if length.is_some() => return Err(error(key, "Duplicate length rule")),
+            "len" => {
+                length = Some(
+                    parse_range(&value)
+                        .ok_or_else(|| error(raw, "Expected a length range like 8-64"))?,
+                )
+            }
+            "class" => {
+                let (class, count) = value
+                    .split_once(':')
+                    .ok_or_else(|| error(raw, "Expected a class rule like digit:1 or upper:1-2"))?;
+                let class = class.parse().map_err(|e: String| error(raw, &e))?;
+                let (min, max) = match count.split_once('-') {
+                    Some(_) => parse_range(count).map(|r| (*r.start(), Some(*r.end()))),
+                    None => count.parse().ok().map(|min| (min, None)),
+                }
+                .ok_or_else(|| error(raw, "Expected a count like 1 or 1-2"))?;
+
+                classes.push(ClassRule { class, min, max });
+            }
+            "re" if regex.is_some() => return Err(error(key, "Duplicate regex rule")),
+            "re" => regex = Some(Regex::new(&value).map_err(|e| error(raw, &e.to_string()))?),
+            key => return Err(error(key, "Unknown rule, expected len, class or re")),
+        }
+    };
+
+    if password.is_empty() {
+        return Err(error(password, "Expected a password"));
+    }
+
+    Ok(LineData {
+        line: idx + 1,
+        occurrences,
+        characters,
+        length,
+        classes,
+        regex,
+        password: String::from(password),
+    })
+}
+
+/// Splits the value of a rule from the rest of the line, returning the raw text of the value,
+/// the value itself and the rest
+///
+/// Quoted values end at the closing quote, others at whitespace or at a `:` followed by
+/// whitespace. Returns `None` for unterminated quotes.
+fn rule_value(input: &str) -> Option<(&str, String, &str)> {
+    if let Some(quoted) = input.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = quoted.char_indices();
+
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => return Some((&input[..idx + 2], value, &quoted[idx + 1..])),
+                '\\' if quoted[idx + 1..].starts_with('"') => {
+                    chars.next();
+                    value.push('"');
+                }
+                c => value.push(c),
+            }
+        }
+
+        return None;
+    }
+
+    let end = input
+        .char_indices()
+        .find(|(idx, c)| {
+            c.is_whitespace()
+                || (*c == ':'
+                    && input[idx + 1..]
+                        .chars()
+                        .next()
+                        .map_or(true, char::is_whitespace))
+        })
+        .map_or(input.len(), |(idx, _)| idx);
+
+    Some((&input[..end], String::from(&input[..end]), &input[end..]))
+}
+
+fn parse_range(s: &str) -> Option<RangeInclusive<usize>> {
+    let (min, max) = s.split_once('-')?;
+
+    Some(min.parse().ok()?..=max.parse().ok()?)
+}
+
+pub fn count_valid(lines: &[LineData], policy: &dyn PasswordPolicy) -> usize {
+    lines.iter().filter(|l| policy.check(l).is_ok()).count()
+}
+
+/// A line that doesn't pass a policy together with the reason why
+#[derive(Debug)]
+pub struct Failure<'a> {
+    pub line: &'a LineData,
+    pub reason: String,
+}
+
+pub fn report<'a>(lines: &'a [LineData], policy: &dyn PasswordPolicy) -> Vec<Failure<'a>> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            policy
+                .check(line)
+                .err()
+                .map(|reason| Failure { line, reason })
+        })
+        .collect()
+}
+
+pub fn passes_occurrence_policy(line: &LineData) -> bool {
+    OccurrencePolicy.check(line).is_ok()
+}
+
+pub fn passes_position_policy(line: &LineData) -> bool {
+    PositionPolicy.check(line).is_ok()
+}
+
+pub mod policy {
+    use itertools::Itertools;
+    use regex::Regex;
+    use std::fmt;
+    use std::ops::RangeInclusive;
+    use std::str::FromStr;
+    use thiserror::Error as ThisError;
+
+    use super::LineData;
+
+    /// A rule passwords have to satisfy, failures are described by a human readable reason
+    pub trait PasswordPolicy: fmt::Display {
+        fn check(&self, line: &LineData) -> Result<(), String>;
+    }
+
+    /// The characters have to occur within the given range of times in total
+    pub struct OccurrencePolicy;
+
+    impl PasswordPolicy for OccurrencePolicy {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            let occurrences = line
+                .password
+                .chars()
+                .filter(|c| line.is_required(*c))
+                .count() as u32;
+
+            if line.occurrences.contains(&occurrences) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} occurs {} times, expected {} to {}",
+                    line.characters.iter().join(","),
+                    occurrences,
+                    line.occurrences.start(),
+                    line.occurrences.end()
+                ))
+            }
+        }
+    }
+
+    impl fmt::Display for OccurrencePolicy {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "occurrences")
+        }
+    }
+
+    /// One of the characters has to be at exactly one of the two 1-based positions
+    pub struct PositionPolicy;
+
+    impl PasswordPolicy for PositionPolicy {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            let (a, b) = (*line.occurrences.start(), *line.occurrences.end());
+            let at = |position: u32| {
+                position
+                    .checked_sub(1)
+                    .and_then(|idx| line.password.chars().nth(idx as usize))
+                    .is_some_and(|c| line.is_required(c))
+            };
+
+            match (at(a), at(b)) {
+                (true, false) | (false, true) => Ok(()),
+                (true, true) => Err(format!(
+                    "{} is at both positions {} and {}",
+                    line.characters.iter().join(","),
+                    a,
+                    b
+                )),
+                (false, false) => Err(format!(
+                    "{} is at neither position {} nor {}",
+                    line.characters.iter().join(","),
+                    a,
+                    b
+                )),
+            }
+        }
+    }
+
+    impl fmt::Display for PositionPolicy {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "positions")
+        }
+    }
+
+    /// The password length has to be within the given range, without a range the length rule
+    /// of the line applies if it has one
+    pub struct LengthPolicy(pub Option<RangeInclusive<usize>>);
+
+    impl PasswordPolicy for LengthPolicy {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            let range = match self.0.as_ref().or(line.length.as_ref()) {
+                Some(range) => range,
+                None => return Ok(()),
+            };
+            let length = line.password.chars().count();
+
+            if range.contains(&length) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "password is {} characters long, expected {} to {}",
+                    length,
+                    range.start(),
+                    range.end()
+                ))
+            }
+        }
+    }
+
+    impl fmt::Display for LengthPolicy {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.0 {
+                Some(range) => write!(f, "length({}, {})", range.start(), range.end()),
+                None => write!(f, "length"),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum CharClass {
+        Lower,
+        Upper,
+        Alpha,
+        Digit,
+        Alnum,
+        Punct,
+    }
+
+    impl CharClass {
+        pub fn contains(&self, c: char) -> bool {
+            match self {
+                Self::Lower => c.is_lowercase(),
+                Self::Upper => c.is_uppercase(),
+                Self::Alpha => c.is_alphabetic(),
+                Self::Digit => c.is_ascii_digit(),
+                Self::Alnum => c.is_alphanumeric(),
+                Self::Punct => c.is_ascii_punctuation(),
+            }
+        }
+    }
+
+    impl FromStr for CharClass {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(match s {
+                "lower" => Self::Lower,
+                "upper" => Self::Upper,
+                "alpha" => Self::Alpha,
+                "digit" => Self::Digit,
+                "alnum" => Self::Alnum,
+                "punct" => Self::Punct,
+                s => return Err(format!("Unknown character class {}", s)),
+            })
+        }
+    }
+
+    impl fmt::Display for CharClass {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "{}",
+                match self {
+                    Self::Lower => "lower",
+                    Self::Upper => "upper",
+                    Self::Alpha => "alpha",
+                    Self::Digit => "digit",
+                    Self::Alnum => "alnum",
+                    Self::Punct => "punct",
+                }
+            )
+        }
+    }
+
+    /// The password has to contain at least `min` and at most `max` characters of a class
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ClassRule {
+        pub class: CharClass,
+        pub min: usize,
+        pub max: Option<usize>,
+    }
+
+    impl ClassRule {
+        pub fn check(&self, password: &str) -> Result<(), String> {
+            let count = password.chars().filter(|c| self.class.contains(*c)).count();
+
+            match self.max {
+                _ if count < self.min => Err(format!(
+                    "password has {} {} characters, expected at least {}",
+                    count, self.class, self.min
+                )),
+                Some(max) if count > max => Err(format!(
+                    "password has {} {} characters, expected at most {}",
+                    count, self.class, max
+                )),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// Formatted like the class rule of an input line, e.g. `digit:1` or `upper:1-2`
+    impl fmt::Display for ClassRule {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}:{}", self.class, self.min)?;
+            if let Some(max) = self.max {
+                write!(f, "-{}", max)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Checks the given class rule, without a rule every class rule of the line applies
+    pub struct CharClassPolicy(pub Option<ClassRule>);
+
+    impl PasswordPolicy for CharClassPolicy {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            let reasons = self
+                .0
+                .iter()
+                .chain(line.classes.iter().filter(|_| self.0.is_none()))
+                .filter_map(|rule| rule.check(&line.password).err())
+                .collect::<Vec<_>>();
+
+            if reasons.is_empty() {
+                Ok(())
+            } else {
+                Err(reasons.join("; "))
+            }
+        }
+    }
+
+    impl fmt::Display for CharClassPolicy {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let rule = match &self.0 {
+                Some(rule) => rule,
+                None => return write!(f, "class"),
+            };
+
+            write!(f, "class({}, {}", rule.class, rule.min)?;
+            if let Some(max) = rule.max {
+                write!(f, ", {}", max)?;
+            }
+            write!(f, ")")
+        }
+    }
+
+    /// The password has to match a regular expression, without one the regex rule of the line
+    /// applies if it has one
+    pub struct RegexPolicy(pub Option<Regex>);
+
+    impl PasswordPolicy for RegexPolicy {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            match self.0.as_ref().or(line.regex.as_ref()) {
+                Some(regex) if !regex.is_match(&line.password) => {
+                    Err(format!("password doesn't match /{}/", regex))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    impl fmt::Display for RegexPolicy {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.0 {
+                Some(regex) => write!(f, "regex({:?})", regex.as_str()),
+                None => write!(f, "regex"),
+            }
+        }
+    }
+
+    /// Passes if every inner policy passes
+    pub struct AllOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+    impl PasswordPolicy for AllOf {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            let reasons = self
+                .0
+                .iter()
+                .filter_map(|policy| policy.check(line).err())
+                .collect::<Vec<_>>();
+
+            if reasons.is_empty() {
+                Ok(())
+            } else {
+                Err(reasons.join("; "))
+            }
+        }
+    }
+
+    impl fmt::Display for AllOf {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_combinator(f, "all", &self.0)
+        }
+    }
+
+    /// Passes if at least one inner policy passes
+    pub struct AnyOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+    impl PasswordPolicy for AnyOf {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            let mut reasons = Vec::new();
+
+            for policy in self.0.iter() {
+                match policy.check(line) {
+                    Ok(()) => return Ok(()),
+                    Err(reason) => reasons.push(reason),
+                }
+            }
+
+            Err(format!("none of {} passes: {}", self, reasons.join("; ")))
+        }
+    }
+
+    impl fmt::Display for AnyOf {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_combinator(f, "any", &self.0)
+        }
+    }
+
+    /// Passes if the inner policy fails
+    pub struct Not(pub Box<dyn PasswordPolicy>);
+
+    impl PasswordPolicy for Not {
+        fn check(&self, line: &LineData) -> Result<(), String> {
+            match self.0.check(line) {
+                Ok(()) => Err(format!("passes {}", self.0)),
+                Err(_) => Ok(()),
+            }
+        }
+    }
+
+    impl fmt::Display for Not {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "not({})", self.0)
+        }
+    }
+
+    fn write_combinator(
+        f: &mut fmt::Formatter<'_>,
+        name: &str,
+        policies: &[Box<dyn PasswordPolicy>],
+    ) -> fmt::Result {
+        write!(f, "{}(", name)?;
+        for (idx, policy) in policies.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", policy)?;
+        }
+        write!(f, ")")
+    }
+
+    #[derive(ThisError, Debug, PartialEq)]
+    #[error("Invalid policy at offset {offset}: {message}")]
+    pub struct PolicyError {
+        pub offset: usize,
+        pub message: String,
+    }
+
+    /// Parses a policy expression like `all(occurrences, not(positions), length(8, 64))`
+    ///
+    /// Expressions consist of the built-in policies `occurrences`, `positions`,
+    /// `length(min, max)`, `class(name, min[, max])` and `regex("pattern")` and the
+    /// combinators `all(..)`, `any(..)` and `not(..)`. Without arguments `length`, `class`
+    /// and `regex` check the rules given by each input line.
+    pub fn parse(expression: &str) -> Result<Box<dyn PasswordPolicy>, PolicyError> {
+        let mut parser = Parser {
+            input: expression,
+            offset: 0,
+        };
+
+        let policy = parser.policy()?;
+        parser.skip_whitespace();
+
+        if parser.offset < expression.len() {
+            return Err(parser.error("Expected the end of the policy"));
+        }
+
+        Ok(policy)
+    }
+
+    struct Parser<'a> {
+        input: &'a str,
+        offset: usize,
+    }
+
+    impl<'a> Parser<'a> {
+        fn policy(&mut self) -> Result<Box<dyn PasswordPolicy>, PolicyError> {
+            self.skip_whitespace();
+            let start = self.offset;
+            let name = self.identifier();
+
+            Ok(match name {
+                "occurrences" => Box::new(OccurrencePolicy),
+                "positions" => Box::new(PositionPolicy),
+                "length" if !self.has_arguments() => Box::new(LengthPolicy(None)),
+                "length" => {
+                    self.expect('(')?;
+                    let min = self.number()?;
+                    self.expect(',')?;
+                    let max = self.number()?;
+                    self.expect(')')?;
+
+                    Box::new(LengthPolicy(Some(min..=max)))
+                }
+                "class" if !self.has_arguments() => Box::new(CharClassPolicy(None)),
+                "class" => {
+                    self.expect('(')?;
+                    self.skip_whitespace();
+                    let class_start = self.offset;
+                    let class = self.identifier().parse().map_err(|message| PolicyError {
+                        offset: class_start,
+                        message,
+                    })?;
+                    self.expect(',')?;
+                    let min = self.number()?;
+                    self.skip_whitespace();
+                    let max = match self.peek() {
+                        Some(',') => {
+                            self.offset += 1;
+                            Some(self.number()?)
+                        }
+                        _ => None,
+                    };
+                    self.expect(')')?;
+
+                    Box::new(CharClassPolicy(Some(ClassRule { class, min, max })))
+                }
+                "regex" if !self.has_arguments() => Box::new(RegexPolicy(None)),
+                "regex" => {
+                    self.expect('(')?;
+                    self.skip_whitespace();
+                    let pattern_start = self.offset;
+                    let pattern = self.string()?;
+                    self.expect(')')?;
+
+                    let regex = Regex::new(&pattern).map_err(|e| PolicyError {
+                        offset: pattern_start,
+                        message: e.to_string(),
+                    })?;
+
+                    Box::new(RegexPolicy(Some(regex)))
+                }
+                "all" => Box::new(AllOf(self.arguments()?)),
+                "any" => Box::new(AnyOf(self.arguments()?)),
+                "not" => {
+                    let mut arguments = self.arguments()?;
+                    if arguments.len() != 1 {
+                        return Err(self.error("not(..) takes exactly one policy"));
+                    }
+
+                    Box::new(Not(arguments.remove(0)))
+                }
+                "" => return Err(self.error("Expected a policy")),
+                name => {
+                    return Err(PolicyError {
+                        offset: start,
+                        message: format!("Unknown policy {}", name),
+                    })
+                }
+            })
+        }
+
+        fn arguments(&mut self) -> Result<Vec<Box<dyn PasswordPolicy>>, PolicyError> {
+            self.expect('(')?;
+            let mut policies = vec![self.policy()?];
+
+            loop {
+                self.skip_whitespace();
+
+                match self.peek() {
+                    Some(',') => {
+                        self.offset += 1;
+                        policies.push(self.policy()?);
+                    }
+                    Some(')') => {
+                        self.offset += 1;
+                        return Ok(policies);
+                    }
+                    _ => return Err(self.error("Expected , or )")),
+                }
+            }
+        }
+
+        /// Whether the next non-whitespace character opens an argument list
+        fn has_arguments(&self) -> bool {
+            self.input[self.offset..].trim_start().starts_with('(')
+        }
+
+        fn number(&mut self) -> Result<usize, PolicyError> {
+            self.skip_whitespace();
+            let start = self.offset;
+            let length = self.input[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(self.input.len() - start);
+            self.offset += length;
+
+            self.input[start..self.offset]
+                .parse()
+                .map_err(|_| PolicyError {
+                    offset: start,
+                    message: String::from("Expected a number"),
+                })
+        }
+
+        /// A double quoted string, `\"` escapes a quote and any other backslash is kept as is
+        fn string(&mut self) -> Result<String, PolicyError> {
+            self.expect('"')?;
+            let mut result = String::new();
+            let mut chars = self.input[self.offset..].char_indices();
+
+            while let Some((idx, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        self.offset += idx + 1;
+                        return Ok(result);
+                    }
+                    '\\' if self.input[self.offset + idx + 1..].starts_with('"') => {
+                        chars.next();
+                        result.push('"');
+                    }
+                    c => result.push(c),
+                }
+            }
+
+            self.offset = self.input.len();
+            Err(self.error("Unterminated string"))
+        }
+
+        fn identifier(&mut self) -> &'a str {
+            let input = self.input;
+            let start = self.offset;
+            let length = input[start..]
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(self.input.len() - start);
+            self.offset += length;
+
+            &input[start..self.offset]
+        }
+
+        fn expect(&mut self, c: char) -> Result<(), PolicyError> {
+            self.skip_whitespace();
+
+            if self.peek() == Some(c) {
+                self.offset += c.len_utf8();
+                Ok(())
+            } else {
+                Err(self.error(&format!("Expected {}", c)))
+            }
+        }
+
+        fn peek(&self) -> Option<char> {
+            self.input[self.offset..].chars().next()
+        }
+
+        fn skip_whitespace(&mut self) {
+            let rest = &self.input[self.offset..];
+            self.offset += rest.len() - rest.trim_start().len();
+        }
+
+        fn error(&self, message: &str) -> PolicyError {
+            PolicyError {
+                offset: self.offset,
+                message: String::from(message),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::policy::{self, PolicyError};
+    use super::{count_valid, parse, parse_all, report};
+
+    const EXAMPLE: &str = r#"
+        1-3 a: abcde
+        1-3 b: cdefg
+        2-9 c: ccccccccc
+    "#;
+
+    #[test]
+    fn test_policy_expressions() {
+        let lines = parse(EXAMPLE.as_bytes()).unwrap();
+        let count = |expression| count_valid(&lines, &*policy::parse(expression).unwrap());
+
+        assert_eq!(2, count("occurrences"));
+        assert_eq!(1, count("positions"));
+        assert_eq!(1, count("all(occurrences, positions)"));
+        assert_eq!(2, count("any(occurrences, positions)"));
+        assert_eq!(1, count("all(occurrences, not(positions))"));
+
+        assert_eq!(
+            "all(occurrences, not(positions))",
+            policy::parse(" all( occurrences ,not(positions) ) ")
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            Some(PolicyError {
+                offset: 4,
+                message: String::from("Unknown policy lengths"),
+            }),
+            policy::parse("any(lengths)").err()
+        );
+        assert!(policy::parse("not(positions, occurrences)").is_err());
+        assert!(policy::parse("all(positions").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let lines = parse(EXAMPLE.as_bytes()).unwrap();
+        let policy = policy::parse("all(occurrences, positions)").unwrap();
+
+        let failures = report(&lines, &*policy)
+            .into_iter()
+            .map(|f| (f.line.line, f.reason))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (
+                    3,
+                    String::from(
+                        "b occurs 0 times, expected 1 to 3; b is at neither position 1 nor 3"
+                    )
+                ),
+                (4, String::from("c is at both positions 2 and 9")),
+            ],
+            failures
+        );
+    }
+
+    #[test]
+    fn test_multiple_characters() {
+        let lines = parse("1-2 a,b: abcab\n1-3 a, b: cbadc\n".as_bytes()).unwrap();
+
+        assert_eq!(vec!['a', 'b'], lines[1].characters);
+        assert_eq!("1-3 a,b: cbadc", lines[1].to_string());
+        assert_eq!(
+            1,
+            count_valid(&lines, &*policy::parse("occurrences").unwrap())
+        );
+        assert_eq!(
+            1,
+            count_valid(&lines, &*policy::parse("positions").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_length_and_class_policies() {
+        let lines = parse("1-3 a: aB3!\n1-3 a: a\n1-3 a: aaaaaa12\n".as_bytes()).unwrap();
+        let valid = |expression| {
+            let policy = policy::parse(expression).unwrap();
+
+            lines
+                .iter()
+                .filter(|line| policy.check(line).is_ok())
+                .map(|line| line.line)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![1, 3], valid("length(2, 10)"));
+        assert_eq!(vec![1], valid("all(class(upper, 1), class(punct, 1))"));
+        assert_eq!(vec![1, 2], valid("class(digit, 0, 1)"));
+        assert_eq!(
+            "class(digit, 0, 1)",
+            policy::parse("class( digit,0 ,1)").unwrap().to_string()
+        );
+        assert!(policy::parse("class(symbol, 1)").is_err());
+        assert!(policy::parse("length(1)").is_err());
+    }
+
+    #[test]
+    fn test_regex_policy() {
+        let lines = parse(EXAMPLE.as_bytes()).unwrap();
+        let policy = policy::parse(r#"regex("^[a-e]+$")"#).unwrap();
+
+        assert_eq!(2, count_valid(&lines, &*policy));
+        assert_eq!(r#"regex("^[a-e]+$")"#, policy.to_string());
+        assert!(policy::parse(r#"regex("(")"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("1-3 a: abc\n1-x a: abc\n".as_bytes()).unwrap_err();
+        assert_eq!((2, 3), (error.line, error.column));
+        assert_eq!("x", error.text);
+
+        let error = parse("1-3 ab: abc\n".as_bytes()).unwrap_err();
+        assert_eq!((1, 5), (error.line, error.column));
+        assert_eq!("ab", error.text);
+
+        assert!(parse("1-3 a abc\n".as_bytes()).is_err());
+        assert!(parse("1-3 a:\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_line_rules() {
+        let input = r#"
+            1-3 a len=4-8 class=digit:1 class=upper:1-2 : aB3cd
+            1-3 a len=4-8: abc
+            1-3 a re="^a[^:\"]+$" class=punct:0-0: a1!
+            1-3 a: whatever
+        "#;
+        let lines = parse(input.as_bytes()).unwrap();
+        let valid = |expression| {
+            let policy = policy::parse(expression).unwrap();
+
+            lines
+                .iter()
+                .filter(|line| policy.check(line).is_ok())
+                .map(|line| line.line)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(Some(4..=8), lines[0].length);
+        assert_eq!(2, lines[0].classes.len());
+        assert_eq!(
+            "1-3 a len=4-8 class=digit:1 class=upper:1-2: aB3cd",
+            lines[0].to_string()
+        );
+        assert_eq!(
+            r#"1-3 a class=punct:0-0 re="^a[^:\"]+$": a1!"#,
+            lines[2].to_string()
+        );
+
+        assert_eq!(vec![2, 4, 5], valid("length"));
+        assert_eq!(vec![2, 3, 5], valid("class"));
+        assert_eq!(vec![2, 3, 4, 5], valid("regex"));
+        assert_eq!(vec![2, 5], valid("all(length, class, regex)"));
+        assert_eq!(vec![3, 4], valid("length(3, 3)"));
+        assert_eq!(
+            "all(length, class, regex)",
+            policy::parse("all( length,class ,regex )")
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let input =
+            "1-3 a: abc\n1-3 a len=x: abc\n\n1-3 a size=3: abc\n1-3 a re=\"(\": abc\n2-4 b: bb\n";
+        let (lines, errors) = parse_all(input.as_bytes());
+
+        assert_eq!(vec![1, 6], lines.iter().map(|l| l.line).collect::<Vec<_>>());
+        assert_eq!(
+            vec![(2, "x"), (4, "size"), (5, "\"(\"")],
+            errors
+                .iter()
+                .map(|e| (e.line, e.text.as_str()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(errors[0], parse(input.as_bytes()).unwrap_err());
+        assert!(parse("1-3 a re=\"abc: abc\n".as_bytes()).is_err());
+        assert!(parse("1-3 a len=1-2 len=1-3: abc\n".as_bytes()).is_err());
+    }
+}
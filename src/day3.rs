@@ -0,0 +1,345 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use crate::grid::{Grid, Point};
+use crate::parse::ParseResult;
+use crate::solver::{Result, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = TreeMap;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(TreeMap::parse(input.as_bytes())?)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Self::Output1> {
+        Ok(map.count_trees(Point { x: 3, y: 1 }))
+    }
+
+    fn part2(map: &Self::Input) -> Result<Self::Output2> {
+        let slopes = [
+            Point { x: 1, y: 1 },
+            Point { x: 3, y: 1 },
+            Point { x: 5, y: 1 },
+            Point { x: 7, y: 1 },
+            Point { x: 1, y: 2 },
+        ];
+
+        Ok(slopes.iter().map(|slope| map.count_trees(*slope)).product())
+    }
+}
+
+/// The map repeats endlessly to the left and right but ends after its last line
+#[derive(Debug, Clone)]
+pub struct TreeMap(Grid<bool>);
+
+impl TreeMap {
+    pub fn parse(reader: impl BufRead) -> ParseResult<Self> {
+        Grid::parse(reader, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("Expected either a tree (#) or an open square (.)"),
+        })
+        .map(Self)
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    pub fn tree_at(&self, pos: Point) -> Option<bool> {
+        if pos.y < 0 || pos.y as usize >= self.height() {
+            return None;
+        }
+
+        self.0.get_wrapping(pos).copied()
+    }
+
+    /// The positions visited when starting at the top left corner and repeatedly moving by
+    /// `slope` until the bottom of the map is passed
+    ///
+    /// # Panics
+    ///
+    /// If the slope doesn't move downwards.
+    pub fn path(&self, slope: Point) -> impl Iterator<Item = Point> + '_ {
+        assert!(slope.y > 0, "slope {:?} doesn't move downwards", slope);
+
+        let mut current = Point { x: 0, y: 0 };
+
+        std::iter::from_fn(move || {
+            let pos = current;
+            current += slope;
+
+            self.tree_at(pos).map(|_| pos)
+        })
+    }
+
+    pub fn count_trees(&self, slope: Point) -> usize {
+        self.path(slope)
+            .filter(|pos| self.tree_at(*pos) == Some(true))
+            .count()
+    }
+
+    /// Counts the trees of every slope within the given ranges, slopes that don't move
+    /// downwards are skipped
+    ///
+    /// The slopes are ordered by the number of trees hit, fewest first.
+    pub fn rank_slopes(
+        &self,
+        dx: RangeInclusive<i32>,
+        dy: RangeInclusive<i32>,
+    ) -> Vec<(Point, usize)> {
+        let mut ranking = dy
+            .filter(|y| *y > 0)
+            .flat_map(|y| dx.clone().map(move |x| Point { x, y }))
+            .map(|slope| (slope, self.count_trees(slope)))
+            .collect::<Vec<_>>();
+
+        ranking.sort_by_key(|(slope, trees)| (*trees, slope.y, slope.x));
+
+        ranking
+    }
+
+    /// Draws the map repeated horizontally as far as the paths of the slopes reach
+    ///
+    /// Visited trees are marked with the hit marker of the slope and visited open squares with
+    /// its miss marker, see [`MARKERS`]. Squares visited by more than one slope are marked
+    /// with `*`. A legend of the markers precedes the map.
+    pub fn render(&self, slopes: &[Point], color: bool) -> String {
+        let mut visits: HashMap<Point, Vec<usize>> = HashMap::new();
+        for (idx, slope) in slopes.iter().enumerate() {
+            for pos in self.path(*slope) {
+                visits.entry(pos).or_default().push(idx);
+            }
+        }
+
+        let width = self.width() as i32;
+        let tile = |x: i32| x.div_euclid(width.max(1));
+        let first_tile = visits.keys().map(|pos| tile(pos.x)).min().unwrap_or(0);
+        let last_tile = visits.keys().map(|pos| tile(pos.x)).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for (idx, slope) in slopes.iter().enumerate() {
+            let (hit, miss) = MARKERS[idx % MARKERS.len()];
+            let legend = format!("{}/{}", hit, miss);
+            output.push_str(&format!(
+                "{}: right {}, down {}\n",
+                paint(&legend, idx, color),
+                slope.x,
+                slope.y
+            ));
+        }
+
+        for y in 0..self.height() as i32 {
+            for x in first_tile * width..(last_tile + 1) * width {
+                let pos = Point { x, y };
+                let is_tree = self.tree_at(pos) == Some(true);
+
+                match visits.get(&pos).map(|v| v.as_slice()) {
+                    Some([idx]) => {
+                        let (hit, miss) = MARKERS[idx % MARKERS.len()];
+                        let marker = if is_tree { hit } else { miss };
+
+                        output.push_str(&paint(&marker.to_string(), *idx, color));
+                    }
+                    Some(_) => output.push('*'),
+                    None => output.push(if is_tree { '#' } else { '.' }),
+                }
+            }
+
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// A route from the top to the bottom row of the map
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub trees: usize,
+    /// The visited positions, x is wrapped into the width of the map
+    pub path: Vec<Point>,
+}
+
+impl TreeMap {
+    /// Finds the route from any square of the top row to any square of the bottom row that
+    /// hits the fewest trees, only using the given moves
+    ///
+    /// The search is Dijkstra's algorithm over the squares of the map with the trees as
+    /// costs, horizontal moves wrap around. Moves leaving the map at the top or bottom are
+    /// not possible.
+    pub fn fewest_trees_route(&self, moves: &[Point]) -> Option<Route> {
+        let (width, height) = (self.width() as i32, self.height() as i32);
+        let index = |pos: Point| (pos.y * width + pos.x) as usize;
+        let cost = |pos: Point| self.tree_at(pos).map_or(0, usize::from);
+
+        let mut trees = vec![usize::MAX; (width * height) as usize];
+        let mut previous = vec![None; trees.len()];
+        let mut queue = BinaryHeap::new();
+
+        for x in 0..width {
+            let pos = Point { x, y: 0 };
+            trees[index(pos)] = cost(pos);
+            queue.push(Reverse((cost(pos), pos.y, pos.x)));
+        }
+
+        while let Some(Reverse((count, y, x))) = queue.pop() {
+            let pos = Point { x, y };
+            if count > trees[index(pos)] {
+                continue;
+            }
+
+            if y == height - 1 {
+                let mut path = vec![pos];
+                while let Some(prev) = previous[index(*path.last().unwrap())] {
+                    path.push(prev);
+                }
+                path.reverse();
+
+                return Some(Route { trees: count, path });
+            }
+
+            for step in moves {
+                let next = Point {
+                    x: (pos.x + step.x).rem_euclid(width),
+                    y: pos.y + step.y,
+                };
+                if next.y < 0 || next.y >= height {
+                    continue;
+                }
+
+                let next_count = count + cost(next);
+                if next_count < trees[index(next)] {
+                    trees[index(next)] = next_count;
+                    previous[index(next)] = Some(pos);
+                    queue.push(Reverse((next_count, next.y, next.x)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Hit and miss markers used by [`TreeMap::render`], assigned to the slopes in order
+pub const MARKERS: [(char, char); 6] = [
+    ('X', 'O'),
+    ('A', 'a'),
+    ('B', 'b'),
+    ('C', 'c'),
+    ('D', 'd'),
+    ('E', 'e'),
+];
+
+/// Colours `text` in the ANSI colour of the slope
+fn paint(text: &str, slope_idx: usize, color: bool) -> String {
+    const COLORS: [u8; 6] = [31, 32, 33, 34, 35, 36];
+
+    if color {
+        format!(
+            "\x1b[1;{}m{}\x1b[0m",
+            COLORS[slope_idx % COLORS.len()],
+            text
+        )
+    } else {
+        String::from(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreeMap;
+    use crate::grid::Point;
+
+    const EXAMPLE: &str = r#"
+        ..##.......
+        #...#...#..
+        .#....#..#.
+        ..#.#...#.#
+        .#...##..#.
+        ..#.##.....
+        .#.#.#....#
+        .#........#
+        #.##...#...
+        #...##....#
+        .#..#...#.#
+    "#;
+
+    #[test]
+    fn test_count_trees() {
+        let map = TreeMap::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(7, map.count_trees(Point::new(3, 1)));
+        assert_eq!(2, map.count_trees(Point::new(1, 2)));
+        // moving left wraps around just like moving right
+        assert_eq!(
+            map.count_trees(Point::new(-8, 1)),
+            map.count_trees(Point::new(3, 1))
+        );
+        assert_eq!(11, map.path(Point::new(-1, 1)).count());
+    }
+
+    #[test]
+    fn test_rank_slopes() {
+        let map = TreeMap::parse(EXAMPLE.as_bytes()).unwrap();
+        let ranking = map.rank_slopes(-2..=7, 0..=2);
+
+        assert_eq!(20, ranking.len());
+        assert!(ranking.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert!(ranking.contains(&(Point::new(5, 1), 3)));
+        assert!(ranking.contains(&(Point::new(7, 1), 4)));
+    }
+
+    #[test]
+    fn test_render() {
+        let map = TreeMap::parse(EXAMPLE.as_bytes()).unwrap();
+
+        let rendered = map.render(&[Point::new(3, 1)], false);
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!("X/O: right 3, down 1", lines[0]);
+        assert_eq!("O.##.......", &lines[1][..11]);
+        assert_eq!(".#....X..#.", &lines[3][..11]);
+        assert_eq!(".#...##..#..X...##..#.", &lines[5][..22]);
+        assert_eq!(12, lines.len());
+        assert!(lines[1..].iter().all(|line| line.len() == 33));
+
+        let rendered = map.render(&[Point::new(1, 1), Point::new(-1, 1)], false);
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!("A/a: right -1, down 1", lines[1]);
+        assert_eq!("..##.......*.##.......", lines[2]);
+        assert_eq!("#...#...#.a#O..#...#..", lines[3]);
+    }
+
+    #[test]
+    fn test_fewest_trees_route() {
+        let map = TreeMap::parse(EXAMPLE.as_bytes()).unwrap();
+
+        let moves = (-3..=3).map(|x| Point::new(x, 1)).collect::<Vec<_>>();
+        let route = map.fewest_trees_route(&moves).unwrap();
+        assert_eq!(0, route.trees);
+        assert_eq!(11, route.path.len());
+        assert!(route
+            .path
+            .iter()
+            .all(|pos| map.tree_at(*pos) == Some(false)));
+
+        // going straight down only leaves the choice of the column
+        let route = map.fewest_trees_route(&[Point::new(0, 1)]).unwrap();
+        assert_eq!(1, route.trees);
+        assert_eq!(Point::new(7, 0), route.path[0]);
+        assert!(route.path.iter().all(|pos| pos.x == 7));
+
+        assert_eq!(None, map.fewest_trees_route(&[Point::new(1, 0)]));
+    }
+}
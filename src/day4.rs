@@ -0,0 +1,424 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::io::BufRead;
+
+use crate::parse::{ParseError, ParseResult, RecordReader};
+use crate::solver::{Result, Solution};
+
+use schema::{PassportError, Schema};
+
+pub type PassportRecord = HashMap<String, String>;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<PassportRecord>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_records(input.as_bytes())?)
+    }
+
+    fn part1(records: &Self::Input) -> Result<Self::Output1> {
+        Ok(count_valid(records, &Schema::passport(), false))
+    }
+
+    fn part2(records: &Self::Input) -> Result<Self::Output2> {
+        Ok(count_valid(records, &Schema::passport(), true))
+    }
+}
+
+pub fn parse_records(reader: impl BufRead) -> ParseResult<Vec<PassportRecord>> {
+    RecordReader::new(reader)
+        .map(|record| {
+            let mut data = HashMap::new();
+            for (idx, line) in record?.lines() {
+                parse_kv_line_into_map(idx, line, &mut data)?;
+            }
+
+            Ok(data)
+        })
+        .collect()
+}
+
+/// Counts the records containing all required fields of the schema, the field values are
+/// only checked if `validate` is set
+pub fn count_valid(records: &[PassportRecord], schema: &Schema, validate: bool) -> usize {
+    records
+        .iter()
+        .filter(|record| schema.check(record, validate).is_ok())
+        .count()
+}
+
+/// The validation failures of a single record, numbered from 1 in input order
+#[derive(Debug, Serialize)]
+pub struct RecordReport {
+    pub record: usize,
+    pub errors: Vec<PassportError>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ValidationReport {
+    pub valid: usize,
+    pub invalid: usize,
+    /// How many records are missing the field or have an invalid value for it
+    pub failures_by_field: BTreeMap<String, usize>,
+    pub records: Vec<RecordReport>,
+}
+
+impl ValidationReport {
+    /// Validates every record against the schema, collecting all failures of each record
+    pub fn new(records: &[PassportRecord], schema: &Schema) -> Self {
+        let mut report = Self::default();
+
+        for (idx, record) in records.iter().enumerate() {
+            let errors = schema.failures(record, true).collect::<Vec<_>>();
+
+            if errors.is_empty() {
+                report.valid += 1;
+            } else {
+                report.invalid += 1;
+            }
+
+            for error in errors.iter() {
+                *report
+                    .failures_by_field
+                    .entry(String::from(error.field()))
+                    .or_default() += 1;
+            }
+
+            report.records.push(RecordReport {
+                record: idx + 1,
+                errors,
+            });
+        }
+
+        report
+    }
+
+    /// The fields ordered by their number of failures, most failures first
+    pub fn most_failed(&self) -> Vec<(&str, usize)> {
+        let mut fields = self
+            .failures_by_field
+            .iter()
+            .map(|(field, count)| (field.as_str(), *count))
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|(field, count)| (std::cmp::Reverse(*count), *field));
+
+        fields
+    }
+}
+
+fn parse_kv_line_into_map(idx: usize, line: &str, data: &mut PassportRecord) -> ParseResult<()> {
+    for kv in line.split_ascii_whitespace() {
+        let (key, value) = parse_kv(kv)
+            .ok_or_else(|| ParseError::new(idx, line, kv, "Expected a key:value pair"))?;
+
+        data.insert(String::from(key), String::from(value));
+    }
+
+    Ok(())
+}
+
+fn parse_kv(kv: &str) -> Option<(&str, &str)> {
+    let mut it = kv.splitn(2, ':');
+
+    Some((it.next()?, it.next()?))
+}
+
+pub mod schema {
+    use regex::Regex;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::convert::TryFrom;
+    use std::fs;
+    use std::path::Path;
+    use thiserror::Error as ThisError;
+
+    use super::PassportRecord;
+
+    /// The fields of a passport, see `schemas/passport.toml` for the format
+    #[derive(Debug, Deserialize)]
+    pub struct Schema {
+        pub fields: BTreeMap<String, FieldSpec>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct FieldSpec {
+        #[serde(default = "required_by_default")]
+        pub required: bool,
+        #[serde(default)]
+        pub validator: Option<Validator>,
+    }
+
+    fn required_by_default() -> bool {
+        true
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub enum Validator {
+        /// An integer within the range
+        Range { min: i64, max: i64 },
+        /// An integer followed by one of the units, each unit has its own range
+        Units { units: BTreeMap<String, Bounds> },
+        /// A value matching the regular expression
+        Regex { pattern: Pattern },
+        /// One of the listed values
+        OneOf { values: Vec<String> },
+        /// A fixed number of decimal digits
+        Digits { length: usize },
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Bounds {
+        pub min: i64,
+        pub max: i64,
+    }
+
+    impl Bounds {
+        fn check(&self, value: &str) -> Result<(), String> {
+            let number = value.parse::<i64>().map_err(|e| e.to_string())?;
+
+            if (self.min..=self.max).contains(&number) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Given number {} is not within the expected range {}..={}",
+                    number, self.min, self.max
+                ))
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(try_from = "String")]
+    pub struct Pattern(pub Regex);
+
+    impl TryFrom<String> for Pattern {
+        type Error = regex::Error;
+
+        fn try_from(pattern: String) -> Result<Self, Self::Error> {
+            Regex::new(&pattern).map(Self)
+        }
+    }
+
+    impl Validator {
+        pub fn check(&self, value: &str) -> Result<(), String> {
+            match self {
+                Self::Range { min, max } => Bounds {
+                    min: *min,
+                    max: *max,
+                }
+                .check(value),
+                Self::Units { units } => units
+                    .iter()
+                    .find_map(|(unit, bounds)| {
+                        value
+                            .strip_suffix(unit.as_str())
+                            .map(|number| bounds.check(number))
+                    })
+                    .unwrap_or_else(|| {
+                        Err(format!(
+                            "Failed to detect measurement unit {}, expected one of {}",
+                            value,
+                            units.keys().cloned().collect::<Vec<_>>().join(", ")
+                        ))
+                    }),
+                Self::Regex { pattern } if pattern.0.is_match(value) => Ok(()),
+                Self::Regex { pattern } => {
+                    Err(format!("{} doesn't match /{}/", value, pattern.0.as_str()))
+                }
+                Self::OneOf { values } if values.iter().any(|v| v == value) => Ok(()),
+                Self::OneOf { values } => Err(format!(
+                    "Unknown value {}, expected one of {}",
+                    value,
+                    values.join(", ")
+                )),
+                Self::Digits { length } if value.len() != *length => Err(format!(
+                    "Invalid length, expected {} got {}",
+                    length,
+                    value.len()
+                )),
+                Self::Digits { .. } if !value.chars().all(|c| c.is_ascii_digit()) => {
+                    Err(format!("Expected only digits in {}", value))
+                }
+                Self::Digits { .. } => Ok(()),
+            }
+        }
+    }
+
+    impl Schema {
+        /// The passport fields described by the puzzle
+        pub fn passport() -> Self {
+            Self::from_toml(include_str!("../schemas/passport.toml"))
+                .expect("the built-in passport schema is valid")
+        }
+
+        /// Loads a schema from a JSON file if the path ends with .json or a TOML file otherwise
+        pub fn load(path: impl AsRef<Path>) -> Result<Self, SchemaError> {
+            let input = fs::read_to_string(&path)?;
+
+            match path.as_ref().extension() {
+                Some(ext) if ext == "json" => Self::from_json(&input),
+                _ => Self::from_toml(&input),
+            }
+        }
+
+        pub fn from_toml(input: &str) -> Result<Self, SchemaError> {
+            Ok(toml::from_str(input)?)
+        }
+
+        pub fn from_json(input: &str) -> Result<Self, SchemaError> {
+            Ok(serde_json::from_str(input)?)
+        }
+
+        /// Checks that all required fields are present, the values are validated as well if
+        /// `validate` is set
+        pub fn check(&self, record: &PassportRecord, validate: bool) -> Result<(), PassportError> {
+            self.failures(record, validate).next().map_or(Ok(()), Err)
+        }
+
+        /// Every missing required field and, if `validate` is set, every invalid value of the
+        /// record in field order
+        pub fn failures<'a>(
+            &'a self,
+            record: &'a PassportRecord,
+            validate: bool,
+        ) -> impl Iterator<Item = PassportError> + 'a {
+            self.fields
+                .iter()
+                .filter_map(move |(key, spec)| match record.get(key) {
+                    None if spec.required => {
+                        Some(PassportError::MissingRequiredField { field: key.clone() })
+                    }
+                    None => None,
+                    Some(value) => spec
+                        .validator
+                        .as_ref()
+                        .filter(|_| validate)?
+                        .check(value)
+                        .err()
+                        .map(|message| PassportError::ValidationFailure {
+                            field: key.clone(),
+                            message,
+                        }),
+                })
+        }
+    }
+
+    #[derive(ThisError, Debug)]
+    pub enum SchemaError {
+        #[error("Failed to read schema: {0}")]
+        Io(#[from] std::io::Error),
+        #[error("Failed to parse schema: {0}")]
+        Toml(#[from] toml::de::Error),
+        #[error("Failed to parse schema: {0}")]
+        Json(#[from] serde_json::Error),
+    }
+
+    #[derive(ThisError, Debug, PartialEq, Serialize)]
+    #[serde(tag = "kind", rename_all = "snake_case")]
+    pub enum PassportError {
+        #[error("Missing required field {field}")]
+        MissingRequiredField { field: String },
+        #[error("Failed validation for field {field}: {message}")]
+        ValidationFailure { field: String, message: String },
+    }
+
+    impl PassportError {
+        pub fn field(&self) -> &str {
+            match self {
+                Self::MissingRequiredField { field } | Self::ValidationFailure { field, .. } => {
+                    field
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::schema::{PassportError, Schema};
+    use super::{count_valid, parse_records, ValidationReport};
+
+    const EXAMPLE: &str = r#"
+        ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+        byr:1937 iyr:2017 cid:147 hgt:183cm
+
+        iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+        hcl:#cfa07d byr:1929
+
+        hcl:#ae17e1 iyr:2013
+        eyr:2024
+        ecl:brn pid:760753108 byr:1931
+        hgt:179cm
+
+        hcl:#cfa07d eyr:2025 pid:166559648
+        iyr:2011 ecl:brn hgt:59in
+    "#;
+
+    #[test]
+    fn test_passport_schema() {
+        let records = parse_records(EXAMPLE.as_bytes()).unwrap();
+        let schema = Schema::passport();
+
+        assert_eq!(2, count_valid(&records, &schema, false));
+        assert_eq!(
+            Err(PassportError::MissingRequiredField {
+                field: String::from("hgt")
+            }),
+            schema.check(&records[1], false)
+        );
+
+        let mut record = records[0].clone();
+        record.insert(String::from("hcl"), String::from("#12345g"));
+        assert!(matches!(
+            schema.check(&record, true),
+            Err(PassportError::ValidationFailure { field, .. }) if field == "hcl"
+        ));
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let mut records = parse_records(EXAMPLE.as_bytes()).unwrap();
+        records[3].insert(String::from("hcl"), String::from("123abc"));
+        records[3].insert(String::from("pid"), String::from("0123456789"));
+        let report = ValidationReport::new(&records, &Schema::passport());
+
+        assert_eq!((2, 2), (report.valid, report.invalid));
+        assert!(report.records[0].errors.is_empty());
+        let fields = report.records[3]
+            .errors
+            .iter()
+            .map(|e| e.field())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["byr", "hcl", "pid"], fields);
+        assert_eq!(
+            vec![("byr", 1), ("hcl", 1), ("hgt", 1), ("pid", 1)],
+            report.most_failed()
+        );
+    }
+
+    #[test]
+    fn test_custom_schema() {
+        let records = parse_records(EXAMPLE.as_bytes()).unwrap();
+        let schema = Schema::from_json(
+            r#"{
+                "fields": {
+                    "cid": { "validator": { "type": "range", "min": 100, "max": 200 } },
+                    "hgt": { "required": false }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(2, count_valid(&records, &schema, false));
+        assert_eq!(1, count_valid(&records, &schema, true));
+        assert!(Schema::from_toml(
+            "[fields.byr]\nvalidator = { type = \"regex\", pattern = \"(\" }"
+        )
+        .is_err());
+    }
+}
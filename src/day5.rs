@@ -0,0 +1,697 @@
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Details, Result, Solution};
+use boarding_pass::{PlaneLayout, Seat};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Seat>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut seats = parse_seats(input.as_bytes())?;
+        seats.sort_by_key(|s| s.id);
+
+        Ok(seats)
+    }
+
+    fn part1(seats: &Self::Input) -> Result<Self::Output1> {
+        Ok(seats
+            .iter()
+            .map(|s| s.id)
+            .max()
+            .ok_or("Couldn't find any seat")?)
+    }
+
+    fn part2(seats: &Self::Input) -> Result<Self::Output2> {
+        let map = SeatMap::new(PlaneLayout::default(), seats);
+
+        Ok(map.your_seat().ok_or("Couldn't find missing seat id")?.id)
+    }
+
+    fn details2(seats: &Self::Input) -> Result<Details> {
+        let map = SeatMap::new(PlaneLayout::default(), seats);
+
+        let mut details = Details::new();
+        details.insert(
+            String::from("highest_seat_id"),
+            seats.iter().map(|s| s.id).max().into(),
+        );
+        details.insert(
+            String::from("missing_seat_id"),
+            map.your_seat().map(|s| s.id).into(),
+        );
+        details.insert(String::from("empty_seats"), map.empty_seats().len().into());
+        details.insert(
+            String::from("occupied_blocks"),
+            map.occupied_blocks().len().into(),
+        );
+
+        Ok(details)
+    }
+}
+
+pub fn parse_seats(reader: impl BufRead) -> ParseResult<Vec<Seat>> {
+    parse::values(reader)
+}
+
+/// Parses the boarding passes of a plane with the given layout, blank lines are skipped
+pub fn parse_seats_in(reader: impl BufRead, layout: PlaneLayout) -> ParseResult<Vec<Seat>> {
+    parse::lines(reader)
+        .filter(|line| !matches!(line, Ok((_, l)) if l.trim().is_empty()))
+        .map(|line| {
+            let (idx, line) = line?;
+            let pass = line.trim();
+
+            layout
+                .decode(pass)
+                .map_err(|e| ParseError::new(idx, &line, pass, e))
+        })
+        .collect()
+}
+
+/// The occupied seats of a plane
+#[derive(Debug, Clone)]
+pub struct SeatMap {
+    layout: PlaneLayout,
+    /// Indexed by seat id
+    occupied: Vec<bool>,
+}
+
+impl SeatMap {
+    /// Seats outside of the layout are ignored
+    pub fn new<'a>(layout: PlaneLayout, seats: impl IntoIterator<Item = &'a Seat>) -> Self {
+        let mut occupied = vec![false; layout.seat_count() as usize];
+        for seat in seats {
+            if layout.seat(seat.row, seat.column).is_ok() {
+                occupied[layout.seat_id(seat.row, seat.column) as usize] = true;
+            }
+        }
+
+        Self { layout, occupied }
+    }
+
+    pub fn layout(&self) -> PlaneLayout {
+        self.layout
+    }
+
+    pub fn is_occupied(&self, row: u32, column: u32) -> bool {
+        row < self.layout.rows()
+            && column < self.layout.columns()
+            && self.occupied[self.layout.seat_id(row, column) as usize]
+    }
+
+    pub fn seats(&self) -> impl Iterator<Item = Seat> + '_ {
+        let layout = self.layout;
+
+        (0..layout.rows())
+            .flat_map(move |row| (0..layout.columns()).map(move |column| (row, column)))
+            .map(move |(row, column)| Seat {
+                row,
+                column,
+                id: layout.seat_id(row, column),
+            })
+    }
+
+    pub fn empty_seats(&self) -> Vec<Seat> {
+        self.seats()
+            .filter(|seat| !self.occupied[seat.id as usize])
+            .collect()
+    }
+
+    /// The maximal runs of occupied seats ordered by seat id, runs continue across rows
+    pub fn occupied_blocks(&self) -> Vec<RangeInclusive<u32>> {
+        let mut blocks = Vec::new();
+        let mut start = None;
+
+        for (id, occupied) in self.occupied.iter().enumerate() {
+            match (start, occupied) {
+                (None, true) => start = Some(id as u32),
+                (Some(first), false) => {
+                    blocks.push(first..=id as u32 - 1);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(first) = start {
+            blocks.push(first..=self.occupied.len() as u32 - 1);
+        }
+
+        blocks
+    }
+
+    /// The number of occupied seats of every row
+    pub fn row_occupancy(&self) -> Vec<usize> {
+        self.occupied
+            .chunks(self.layout.columns().max(1) as usize)
+            .map(|row| row.iter().filter(|occupied| **occupied).count())
+            .collect()
+    }
+
+    /// The first empty seat whose neighbours by seat id are both occupied
+    pub fn your_seat(&self) -> Option<Seat> {
+        self.empty_seats().into_iter().find(|seat| {
+            let id = seat.id as usize;
+
+            id > 0 && self.occupied[id - 1] && self.occupied.get(id + 1) == Some(&true)
+        })
+    }
+}
+
+impl SeatMap {
+    /// Draws the cabin row by row, marking occupied seats with `#`, free seats with `.` and
+    /// your seat with `X`
+    ///
+    /// Rows are labelled by their number, columns by the last digit of their number.
+    pub fn render(&self) -> String {
+        let label_width = self.layout.rows().saturating_sub(1).to_string().len();
+        let your_seat = self.your_seat();
+
+        let mut output = format!("{:>width$} ", "", width = label_width);
+        for column in 0..self.layout.columns() {
+            output.push_str(&(column % 10).to_string());
+        }
+        output.push('\n');
+
+        for row in 0..self.layout.rows() {
+            output.push_str(&format!("{:>width$} ", row, width = label_width));
+            for column in 0..self.layout.columns() {
+                let seat = Seat {
+                    row,
+                    column,
+                    id: self.layout.seat_id(row, column),
+                };
+                output.push(match self.state(&seat, your_seat) {
+                    SeatState::Yours => 'X',
+                    SeatState::Occupied => '#',
+                    SeatState::Free => '.',
+                });
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Draws the cabin as an SVG image with row and column labels, your seat is highlighted
+    pub fn render_svg(&self) -> String {
+        const SEAT: u64 = 16;
+        const GAP: u64 = 4;
+        const LABELS: u64 = 32;
+
+        let offset = |n: u32| LABELS + u64::from(n) * (SEAT + GAP);
+        let width = offset(self.layout.columns());
+        let height = offset(self.layout.rows());
+        let your_seat = self.your_seat();
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" \
+             viewBox=\"0 0 {0} {1}\" font-family=\"monospace\" font-size=\"10\">\n",
+            width, height
+        );
+        svg.push_str(&format!(
+            "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+            width, height
+        ));
+
+        for column in 0..self.layout.columns() {
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+                offset(column) + SEAT / 2,
+                LABELS - GAP * 2,
+                column
+            ));
+        }
+
+        for row in 0..self.layout.rows() {
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>\n",
+                LABELS - GAP * 2,
+                offset(row) + SEAT - GAP,
+                row
+            ));
+        }
+
+        for seat in self.seats() {
+            let (class, fill) = match self.state(&seat, your_seat) {
+                SeatState::Yours => ("yours", "#e94e3c"),
+                SeatState::Occupied => ("occupied", "#4a90d9"),
+                SeatState::Free => ("free", "#e0e0e0"),
+            };
+
+            svg.push_str(&format!(
+                "<rect class=\"{}\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"3\" \
+                 fill=\"{}\"><title>row {} column {} id {}</title></rect>\n",
+                class,
+                offset(seat.column),
+                offset(seat.row),
+                SEAT,
+                SEAT,
+                fill,
+                seat.row,
+                seat.column,
+                seat.id
+            ));
+        }
+
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    fn state(&self, seat: &Seat, your_seat: Option<Seat>) -> SeatState {
+        if Some(*seat) == your_seat {
+            SeatState::Yours
+        } else if self.occupied[seat.id as usize] {
+            SeatState::Occupied
+        } else {
+            SeatState::Free
+        }
+    }
+}
+
+enum SeatState {
+    Occupied,
+    Free,
+    Yours,
+}
+
+pub mod boarding_pass {
+    use std::str::FromStr;
+    use thiserror::Error as ThisError;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Seat {
+        pub row: u32,
+        pub column: u32,
+        pub id: u32,
+    }
+
+    impl FromStr for Seat {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            decode(s)
+        }
+    }
+
+    impl Seat {
+        /// The boarding pass of the seat in the default plane
+        ///
+        /// # Panics
+        ///
+        /// If the seat lies outside of the default plane, use [`PlaneLayout::encode`] for
+        /// other planes.
+        pub fn encode(&self) -> String {
+            PlaneLayout::default()
+                .encode(self)
+                .unwrap_or_else(|e| panic!("{}", e))
+        }
+    }
+
+    /// The number of rows and columns of a plane
+    ///
+    /// Boarding passes use as many row and column characters as needed to binary encode
+    /// the highest row and column.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct PlaneLayout {
+        rows: u32,
+        columns: u32,
+    }
+
+    impl Default for PlaneLayout {
+        fn default() -> Self {
+            Self {
+                rows: 128,
+                columns: 8,
+            }
+        }
+    }
+
+    impl PlaneLayout {
+        /// Fails unless the plane has at least one seat, every seat id fits into a `u32` and a
+        /// boarding pass encodes at most 32 bits
+        pub fn new(rows: u32, columns: u32) -> Result<Self, Error> {
+            let layout = Self { rows, columns };
+
+            match rows.checked_mul(columns) {
+                Some(seats) if seats > 0 && layout.pass_len() <= 32 => Ok(layout),
+                _ => Err(Error::InvalidLayout { rows, columns }),
+            }
+        }
+
+        pub fn rows(&self) -> u32 {
+            self.rows
+        }
+
+        pub fn columns(&self) -> u32 {
+            self.columns
+        }
+
+        /// The number of seats, which is one more than the highest seat id
+        pub fn seat_count(&self) -> u32 {
+            self.rows * self.columns
+        }
+
+        pub fn row_bits(&self) -> usize {
+            bits_for(self.rows)
+        }
+
+        pub fn column_bits(&self) -> usize {
+            bits_for(self.columns)
+        }
+
+        /// The length of a boarding pass
+        pub fn pass_len(&self) -> usize {
+            self.row_bits() + self.column_bits()
+        }
+
+        /// The seats are numbered row by row
+        pub fn seat_id(&self, row: u32, column: u32) -> u32 {
+            if self.columns.is_power_of_two() {
+                row << self.column_bits() | column
+            } else {
+                row * self.columns + column
+            }
+        }
+
+        pub fn seat(&self, row: u32, column: u32) -> Result<Seat, Error> {
+            self.check_range(&Part::Row, row)?;
+            self.check_range(&Part::Column, column)?;
+
+            Ok(Seat {
+                row,
+                column,
+                id: self.seat_id(row, column),
+            })
+        }
+
+        pub fn decode(&self, input: &str) -> Result<Seat, Error> {
+            let len = input.chars().count();
+            if len != self.pass_len() {
+                return Err(Error::InvalidInputLength {
+                    given: len,
+                    expected: self.pass_len(),
+                });
+            }
+
+            let value = decode_bits(input, self.row_bits())?;
+            let row = value.checked_shr(self.column_bits() as u32).unwrap_or(0);
+            let column = (u64::from(value) & ((1 << self.column_bits()) - 1)) as u32;
+            self.check_range(&Part::Row, row)?;
+            self.check_range(&Part::Column, column)?;
+
+            Ok(Seat {
+                row,
+                column,
+                // with a power of two columns the pass is the binary seat id
+                id: if self.columns.is_power_of_two() {
+                    value
+                } else {
+                    self.seat_id(row, column)
+                },
+            })
+        }
+
+        pub fn encode(&self, seat: &Seat) -> Result<String, Error> {
+            self.check_range(&Part::Row, seat.row)?;
+            self.check_range(&Part::Column, seat.column)?;
+
+            Ok(encode_part(seat.row, self.row_bits(), &Part::Row)
+                + &encode_part(seat.column, self.column_bits(), &Part::Column))
+        }
+
+        fn check_range(&self, part: &Part, value: u32) -> Result<(), Error> {
+            let size = match part {
+                Part::Row => self.rows,
+                Part::Column => self.columns,
+            };
+
+            if value < size {
+                Ok(())
+            } else {
+                Err(Error::OutOfRange {
+                    part: part.name(),
+                    value,
+                    size,
+                })
+            }
+        }
+    }
+
+    /// The number of bits needed to encode the values 0 up to `count` exclusive
+    fn bits_for(count: u32) -> usize {
+        match count {
+            0 | 1 => 0,
+            count => (32 - (count - 1).leading_zeros()) as usize,
+        }
+    }
+
+    /// Decodes a boarding pass of the default plane
+    pub fn decode(input: &str) -> Result<Seat, Error> {
+        PlaneLayout::default().decode(input)
+    }
+
+    #[derive(Debug)]
+    pub struct PartEncoding {
+        low: char,
+        high: char,
+    }
+
+    enum Part {
+        Column,
+        Row,
+    }
+
+    impl Part {
+        fn name(&self) -> &'static str {
+            match self {
+                Part::Column => "column",
+                Part::Row => "row",
+            }
+        }
+
+        fn get_encoding(&self) -> &'static PartEncoding {
+            match self {
+                Part::Row => &PartEncoding {
+                    low: 'F',
+                    high: 'B',
+                },
+                Part::Column => &PartEncoding {
+                    low: 'L',
+                    high: 'R',
+                },
+            }
+        }
+    }
+
+    /// Reads the whole pass as one binary number, most significant bit first, the first
+    /// `row_bits` characters use the row encoding and the rest the column encoding
+    fn decode_bits(input: &str, row_bits: usize) -> Result<u32, Error> {
+        input.chars().enumerate().try_fold(0, |value, (idx, c)| {
+            let part = if idx < row_bits {
+                Part::Row
+            } else {
+                Part::Column
+            };
+            let encoding = part.get_encoding();
+
+            let bit = if c == encoding.low {
+                0
+            } else if c == encoding.high {
+                1
+            } else {
+                return Err(Error::UnexpectedCharacter {
+                    given: c,
+                    expected: encoding,
+                });
+            };
+
+            Ok(value << 1 | bit)
+        })
+    }
+
+    fn encode_part(value: u32, bits: usize, part: &Part) -> String {
+        let encoding = part.get_encoding();
+
+        (0..bits)
+            .rev()
+            .map(|bit| {
+                if value >> bit & 1 == 1 {
+                    encoding.high
+                } else {
+                    encoding.low
+                }
+            })
+            .collect()
+    }
+
+    #[derive(ThisError, Debug)]
+    pub enum Error {
+        #[error(
+            "Encountered an unexpected character during decoding {given} expected: {expected:?}"
+        )]
+        UnexpectedCharacter {
+            given: char,
+            expected: &'static PartEncoding,
+        },
+        #[error("Got an input with invalid length: {given}, expected {expected}")]
+        InvalidInputLength { given: usize, expected: usize },
+        #[error("The {part} {value} is outside of the plane with {size} {part}s")]
+        OutOfRange {
+            part: &'static str,
+            value: u32,
+            size: u32,
+        },
+        #[error("A plane with {rows} rows and {columns} columns has no or too many seats")]
+        InvalidLayout { rows: u32, columns: u32 },
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_decode_bits() -> std::result::Result<(), Box<dyn std::error::Error>> {
+            assert_eq!(44, decode_bits("FBFBBFF", 7)?);
+            assert_eq!(5, decode_bits("RLR", 0)?);
+            assert_eq!(70, decode_bits("BFFFBBF", 7)?);
+            assert_eq!(7, decode_bits("RRR", 0)?);
+            assert_eq!(357, decode_bits("FBFBBFFRLR", 7)?);
+            assert!(decode_bits("FBFBBFFRLR", 8).is_err());
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_parse_seat() -> std::result::Result<(), Box<dyn std::error::Error>> {
+            assert_eq!(
+                Seat {
+                    row: 70,
+                    column: 7,
+                    id: 567
+                },
+                decode("BFFFBBFRRR")?
+            );
+
+            assert_eq!(
+                Seat {
+                    row: 14,
+                    column: 7,
+                    id: 119
+                },
+                decode("FFFBBBFRRR")?
+            );
+
+            assert_eq!(
+                Seat {
+                    row: 102,
+                    column: 4,
+                    id: 820
+                },
+                decode("BBFFBBFRLL")?
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_encode_round_trip() -> std::result::Result<(), Box<dyn std::error::Error>> {
+            assert_eq!("FBFBBFFRLR", decode("FBFBBFFRLR")?.encode());
+
+            let layouts = [
+                PlaneLayout::default(),
+                PlaneLayout::new(100, 6)?,
+                PlaneLayout::new(1, 1)?,
+            ];
+            for layout in layouts.iter() {
+                for row in 0..layout.rows {
+                    for column in 0..layout.columns {
+                        let seat = layout.seat(row, column)?;
+                        let pass = layout.encode(&seat)?;
+
+                        assert_eq!(layout.pass_len(), pass.len());
+                        assert_eq!(seat, layout.decode(&pass)?);
+                    }
+                }
+            }
+
+            let layout = layouts[1];
+            assert_eq!((7, 3), (layout.row_bits(), layout.column_bits()));
+            assert_eq!(599, layout.seat(99, 5)?.id);
+            assert!(layout.decode("BBBBBBBLLL").is_err());
+            assert!(layout.decode("FFFFFFFRRL").is_err());
+            assert!(layout.decode("FFFFFFFRRÄ").is_err());
+            assert!(layout.encode(&decode("FFFFFFFRRL")?).is_err());
+
+            assert!(PlaneLayout::new(0, 8).is_err());
+            assert!(PlaneLayout::new(1 << 16, 1 << 16).is_err());
+            assert!(PlaneLayout::new(3, (1 << 30) + 1).is_err());
+
+            let layout = PlaneLayout::new(1, u32::MAX)?;
+            let pass = format!("{}R", "L".repeat(31));
+            let seat = layout.decode(&pass)?;
+            assert_eq!((0, 1), (seat.row, seat.column));
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::boarding_pass::PlaneLayout;
+    use super::SeatMap;
+
+    #[test]
+    fn test_seat_map() {
+        let layout = PlaneLayout::new(4, 3).unwrap();
+        let seats = [0, 1, 3, 4, 6, 8, 9]
+            .iter()
+            .map(|id| layout.seat(id / 3, id % 3).unwrap())
+            .collect::<Vec<_>>();
+        let map = SeatMap::new(layout, &seats);
+
+        assert!(map.is_occupied(1, 0));
+        assert!(!map.is_occupied(1, 2));
+        assert!(!map.is_occupied(4, 0));
+        assert_eq!(
+            vec![2, 5, 7, 10, 11],
+            map.empty_seats().iter().map(|s| s.id).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![0..=1, 3..=4, 6..=6, 8..=9], map.occupied_blocks());
+        assert_eq!(vec![2, 2, 2, 1], map.row_occupancy());
+        assert_eq!(Some(2), map.your_seat().map(|s| s.id));
+    }
+
+    #[test]
+    fn test_render() {
+        let layout = PlaneLayout::new(12, 3).unwrap();
+        let seats = (3..30)
+            .filter(|id| *id != 7)
+            .map(|id| layout.seat(id / 3, id % 3).unwrap())
+            .collect::<Vec<_>>();
+        let map = SeatMap::new(layout, &seats);
+
+        let rendered = map.render();
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(13, lines.len());
+        assert_eq!("   012", lines[0]);
+        assert_eq!(" 0 ...", lines[1]);
+        assert_eq!(" 2 #X#", lines[3]);
+        assert_eq!("11 ...", lines[12]);
+
+        let svg = map.render_svg();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(36, svg.matches("<rect class=").count());
+        assert_eq!(26, svg.matches("class=\"occupied\"").count());
+        assert_eq!(1, svg.matches("class=\"yours\"").count());
+        assert!(svg.contains(">11</text>"));
+    }
+}
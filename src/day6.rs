@@ -0,0 +1,283 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::parse::{ParseError, ParseResult, RecordReader};
+use crate::solver::{Result, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Group>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_groups(input.as_bytes())?)
+    }
+
+    fn part1(groups: &Self::Input) -> Result<Self::Output1> {
+        Ok(Query::Anyone.total(groups))
+    }
+
+    fn part2(groups: &Self::Input) -> Result<Self::Output2> {
+        Ok(Query::Everyone.total(groups))
+    }
+}
+
+/// A set of the questions a through z, one bit per question
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Answers(u32);
+
+impl Answers {
+    pub const ALL: Self = Self((1 << 26) - 1);
+
+    pub fn contains(self, question: char) -> bool {
+        question.is_ascii_lowercase() && self.0 & bit(question) != 0
+    }
+
+    /// # Panics
+    ///
+    /// If the question isn't one of a through z, `parse_groups` rejects those beforehand.
+    pub fn insert(&mut self, question: char) {
+        self.0 |= bit(question);
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn questions(self) -> impl Iterator<Item = char> {
+        QUESTIONS.filter(move |q| self.contains(*q))
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.questions().try_for_each(|q| write!(f, "{}", q))
+    }
+}
+
+const QUESTIONS: std::ops::RangeInclusive<char> = 'a'..='z';
+
+/// # Panics
+///
+/// If the question isn't one of a through z.
+fn bit(question: char) -> u32 {
+    assert!(
+        question.is_ascii_lowercase(),
+        "Unknown question {}",
+        question
+    );
+
+    1 << (question as u32 - 'a' as u32)
+}
+
+/// The answers of the people in a group
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    /// Zero based index of the first line of the group
+    pub line_idx: usize,
+    pub people: Vec<Answers>,
+}
+
+impl Group {
+    /// The questions anyone answered with yes
+    pub fn anyone(&self) -> Answers {
+        self.people
+            .iter()
+            .fold(Answers::default(), |a, b| a.union(*b))
+    }
+
+    /// The questions everyone answered with yes
+    pub fn everyone(&self) -> Answers {
+        if self.people.is_empty() {
+            return Answers::default();
+        }
+
+        self.people
+            .iter()
+            .fold(Answers::ALL, |a, b| a.intersection(*b))
+    }
+
+    /// The questions at least `k` people answered with yes
+    pub fn at_least(&self, k: usize) -> Answers {
+        self.matching(|count| count >= k)
+    }
+
+    /// The questions exactly one person answered with yes
+    pub fn exactly_one(&self) -> Answers {
+        self.matching(|count| count == 1)
+    }
+
+    /// The number of people answering each question with yes, a first
+    pub fn frequencies(&self) -> [usize; 26] {
+        let mut frequencies = [0; 26];
+        for (frequency, question) in frequencies.iter_mut().zip(QUESTIONS) {
+            *frequency = self.people.iter().filter(|a| a.contains(question)).count();
+        }
+
+        frequencies
+    }
+
+    fn matching(&self, filter: impl Fn(usize) -> bool) -> Answers {
+        let mut answers = Answers::default();
+        for (frequency, question) in self.frequencies().iter().zip(QUESTIONS) {
+            if filter(*frequency) {
+                answers.insert(question);
+            }
+        }
+
+        answers
+    }
+}
+
+/// Selects the questions of a group that are counted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Query {
+    Anyone,
+    Everyone,
+    AtLeast(usize),
+    ExactlyOne,
+}
+
+impl Query {
+    pub fn answers(&self, group: &Group) -> Answers {
+        match self {
+            Self::Anyone => group.anyone(),
+            Self::Everyone => group.everyone(),
+            Self::AtLeast(k) => group.at_least(*k),
+            Self::ExactlyOne => group.exactly_one(),
+        }
+    }
+
+    /// The sum of the selected questions of all groups
+    pub fn total(&self, groups: &[Group]) -> usize {
+        groups.iter().map(|group| self.answers(group).len()).sum()
+    }
+}
+
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "anyone" => Self::Anyone,
+            "everyone" => Self::Everyone,
+            "exactly-one" => Self::ExactlyOne,
+            s => match s.strip_prefix("at-least=") {
+                Some(k) => Self::AtLeast(
+                    k.parse()
+                        .map_err(|e| format!("Invalid number of people {:?}: {}", k, e))?,
+                ),
+                None => {
+                    return Err(format!(
+                        "Unknown query {}, expected anyone, everyone, exactly-one or at-least=K",
+                        s
+                    ))
+                }
+            },
+        })
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Anyone => write!(f, "anyone"),
+            Self::Everyone => write!(f, "everyone"),
+            Self::AtLeast(k) => write!(f, "at-least={}", k),
+            Self::ExactlyOne => write!(f, "exactly-one"),
+        }
+    }
+}
+
+/// Reads the answer groups, blank lines separate the groups
+pub fn parse_groups(reader: impl BufRead) -> ParseResult<Vec<Group>> {
+    RecordReader::new(reader)
+        .map(|record| {
+            let record = record?;
+            let people = record
+                .lines()
+                .map(|(idx, line)| {
+                    let mut answers = Answers::default();
+
+                    for (pos, c) in line.trim().char_indices() {
+                        if !c.is_ascii_lowercase() {
+                            return Err(ParseError::new(
+                                idx,
+                                line,
+                                &line.trim()[pos..pos + c.len_utf8()],
+                                "Expected questions a through z",
+                            ));
+                        }
+
+                        answers.insert(c);
+                    }
+
+                    Ok(answers)
+                })
+                .collect::<ParseResult<_>>()?;
+
+            Ok(Group {
+                line_idx: record.line_idx,
+                people,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_groups, Query};
+
+    const EXAMPLE: &str = r#"
+        abc
+
+        a
+        b
+        c
+
+        ab
+        ac
+
+        a
+        a
+        a
+        a
+
+        b
+    "#;
+
+    #[test]
+    fn test_queries() {
+        let groups = parse_groups(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(11, Query::Anyone.total(&groups));
+        assert_eq!(6, Query::Everyone.total(&groups));
+        assert_eq!(2, Query::AtLeast(2).total(&groups));
+        assert_eq!(9, Query::ExactlyOne.total(&groups));
+
+        let group = &groups[2];
+        assert_eq!(7, group.line_idx);
+        assert_eq!("abc", group.anyone().to_string());
+        assert_eq!("a", group.at_least(2).to_string());
+        assert_eq!("bc", group.exactly_one().to_string());
+        assert_eq!([2, 1, 1], group.frequencies()[..3]);
+
+        assert_eq!(Ok(Query::AtLeast(3)), "at-least=3".parse());
+        assert!("most".parse::<Query>().is_err());
+    }
+}
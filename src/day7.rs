@@ -0,0 +1,368 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::io::BufRead;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub type BagRules = HashMap<String, HashMap<String, usize>>;
+
+const SEARCHED_BAG: &str = "shiny gold";
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = BagGraph;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(BagGraph::parse(input.as_bytes())?)
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Self::Output1> {
+        Ok(graph.containers_of(SEARCHED_BAG)?.len())
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Self::Output2> {
+        Ok(graph.contents_count(SEARCHED_BAG)?)
+    }
+}
+
+/// The bag rules as a directed graph from every bag to the bags it directly contains
+///
+/// Bags only mentioned as content are part of the graph without any content.
+#[derive(Debug, Clone)]
+pub struct BagGraph {
+    /// Sorted by name, the index of a bag is its id
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    /// The directly contained bags and their count by bag id
+    contents: Vec<Vec<(usize, usize)>>,
+    /// The bags directly containing a bag by bag id
+    containers: Vec<Vec<usize>>,
+}
+
+impl BagGraph {
+    pub fn parse(reader: impl BufRead) -> ParseResult<Self> {
+        Ok(Self::new(&parse(reader)?))
+    }
+
+    pub fn new(rules: &BagRules) -> Self {
+        let names = rules
+            .iter()
+            .flat_map(|(outer, content)| std::iter::once(outer).chain(content.keys()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let ids = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| (name.clone(), id))
+            .collect::<HashMap<_, _>>();
+
+        let mut contents = vec![Vec::new(); names.len()];
+        let mut containers = vec![Vec::new(); names.len()];
+        for (outer, content) in rules.iter() {
+            let outer = ids[outer];
+            for (inner, count) in content.iter() {
+                let inner = ids[inner];
+                contents[outer].push((inner, *count));
+                containers[inner].push(outer);
+            }
+        }
+
+        for edges in contents.iter_mut() {
+            edges.sort_unstable();
+        }
+        for edges in containers.iter_mut() {
+            edges.sort_unstable();
+        }
+
+        Self {
+            names,
+            ids,
+            contents,
+            containers,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The names of all bags in alphabetical order
+    pub fn bags(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(|name| name.as_str())
+    }
+
+    /// The bags directly contained in `bag` together with their count
+    pub fn contents(&self, bag: &str) -> std::result::Result<Vec<(&str, usize)>, GraphError> {
+        Ok(self.contents[self.id(bag)?]
+            .iter()
+            .map(|(inner, count)| (self.names[*inner].as_str(), *count))
+            .collect())
+    }
+
+    /// Every bag that eventually contains `bag`
+    pub fn containers_of(&self, bag: &str) -> std::result::Result<BTreeSet<&str>, GraphError> {
+        let mut visited = vec![false; self.len()];
+        let mut queue = VecDeque::from(vec![self.id(bag)?]);
+
+        while let Some(id) = queue.pop_front() {
+            for outer in self.containers[id].iter() {
+                if !visited[*outer] {
+                    visited[*outer] = true;
+                    queue.push_back(*outer);
+                }
+            }
+        }
+
+        Ok(visited
+            .iter()
+            .zip(self.names.iter())
+            .filter(|(visited, _)| **visited)
+            .map(|(_, name)| name.as_str())
+            .collect())
+    }
+
+    /// The total number of bags inside of `bag`
+    ///
+    /// Fails if the rules require `bag` to contain itself or the count doesn't fit into a
+    /// usize.
+    pub fn contents_count(&self, bag: &str) -> std::result::Result<usize, GraphError> {
+        let mut counts = vec![Count::Unknown; self.len()];
+
+        self.count_contents(self.id(bag)?, &mut counts)
+    }
+
+    fn count_contents(
+        &self,
+        id: usize,
+        counts: &mut [Count],
+    ) -> std::result::Result<usize, GraphError> {
+        match counts[id] {
+            Count::Known(count) => return Ok(count),
+            Count::Counting => return Err(GraphError::Cycle(self.names[id].clone())),
+            Count::Unknown => counts[id] = Count::Counting,
+        }
+
+        let overflow = || GraphError::Overflow(self.names[id].clone());
+        let mut total = 0usize;
+        for (inner, count) in self.contents[id].iter() {
+            let inner_total = self.count_contents(*inner, counts)?;
+            let bags = inner_total
+                .checked_add(1)
+                .and_then(|bags| bags.checked_mul(*count))
+                .ok_or_else(overflow)?;
+
+            total = total.checked_add(bags).ok_or_else(overflow)?;
+        }
+
+        counts[id] = Count::Known(total);
+
+        Ok(total)
+    }
+
+    /// All bags ordered so that every bag comes before the bags it contains
+    pub fn topological_order(&self) -> std::result::Result<Vec<&str>, GraphError> {
+        let mut remaining_containers = self
+            .containers
+            .iter()
+            .map(|containers| containers.len())
+            .collect::<Vec<_>>();
+        let mut queue = remaining_containers
+            .iter()
+            .enumerate()
+            .filter(|(_, remaining)| **remaining == 0)
+            .map(|(id, _)| id)
+            .collect::<VecDeque<_>>();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(self.names[id].as_str());
+
+            for (inner, _) in self.contents[id].iter() {
+                remaining_containers[*inner] -= 1;
+                if remaining_containers[*inner] == 0 {
+                    queue.push_back(*inner);
+                }
+            }
+        }
+
+        match remaining_containers
+            .iter()
+            .position(|remaining| *remaining > 0)
+        {
+            Some(id) => Err(GraphError::Cycle(self.names[id].clone())),
+            None => Ok(order),
+        }
+    }
+
+    fn id(&self, bag: &str) -> std::result::Result<usize, GraphError> {
+        self.ids
+            .get(bag)
+            .copied()
+            .ok_or_else(|| GraphError::UnknownBag(String::from(bag)))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Count {
+    Unknown,
+    Counting,
+    Known(usize),
+}
+
+#[derive(ThisError, Debug, PartialEq)]
+pub enum GraphError {
+    #[error("Unknown bag {0}")]
+    UnknownBag(String),
+    #[error("The {0} bag is part of a cycle and would contain itself")]
+    Cycle(String),
+    #[error("The number of bags inside of the {0} bag is too large")]
+    Overflow(String),
+}
+
+pub fn parse(reader: impl BufRead) -> ParseResult<BagRules> {
+    let mut data = HashMap::new();
+
+    for line in parse::lines(reader) {
+        let (idx, line) = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (container, content) = {
+            let mut si = line.splitn(2, "contain");
+            match (si.next(), si.next()) {
+                (Some(container), Some(content)) => (container, content),
+                _ => {
+                    return Err(ParseError::new(
+                        idx,
+                        &line,
+                        line.trim(),
+                        "Expected a rule like \"<color> bags contain <content>.\"",
+                    ))
+                }
+            }
+        };
+
+        let content = content.trim().trim_end_matches('.');
+        let content = match content {
+            "no other bags" => HashMap::new(),
+            _ => content
+                .split(',')
+                .map(|s| {
+                    let s = s.trim();
+                    let mut si = s.splitn(2, ' ');
+                    let count = si.next().and_then(|count| count.parse::<usize>().ok());
+
+                    match (count, si.next()) {
+                        (Some(count), Some(color)) => {
+                            Ok((String::from(strip_bag_suffix(color)), count))
+                        }
+                        _ => Err(ParseError::new(
+                            idx,
+                            &line,
+                            s,
+                            "Expected bag content like \"<count> <color> bags\"",
+                        )),
+                    }
+                })
+                .collect::<ParseResult<_>>()?,
+        };
+
+        data.insert(String::from(strip_bag_suffix(container)), content);
+    }
+
+    Ok(data)
+}
+
+fn strip_bag_suffix(input: &str) -> &str {
+    input
+        .trim()
+        .trim_end_matches("bags")
+        .trim_end_matches("bag")
+        .trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BagGraph, GraphError};
+
+    const EXAMPLE: &str = r#"
+        light red bags contain 1 bright white bag, 2 muted yellow bags.
+        dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+        bright white bags contain 1 shiny gold bag.
+        muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+        shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+        dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+        vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+        faded blue bags contain no other bags.
+        dotted black bags contain no other bags.
+    "#;
+
+    #[test]
+    fn test_bag_graph() {
+        let graph = BagGraph::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(9, graph.len());
+        assert_eq!(
+            vec!["bright white", "dark orange", "light red", "muted yellow"],
+            graph
+                .containers_of("shiny gold")
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(32, graph.contents_count("shiny gold").unwrap());
+        assert_eq!(0, graph.contents_count("faded blue").unwrap());
+        assert_eq!(
+            Err(GraphError::UnknownBag(String::from("plaid"))),
+            graph.contents_count("plaid")
+        );
+
+        let order = graph.topological_order().unwrap();
+        let position = |bag: &str| order.iter().position(|b| *b == bag).unwrap();
+        assert_eq!(9, order.len());
+        for outer in graph.bags() {
+            for (inner, _) in graph.contents(outer).unwrap() {
+                assert!(position(outer) < position(inner));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let graph = BagGraph::parse(
+            "a bags contain 1 b bag.\nb bags contain 2 c bags.\nc bags contain 1 a bag.\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert!(matches!(
+            graph.contents_count("a"),
+            Err(GraphError::Cycle(_))
+        ));
+        assert!(matches!(
+            graph.topological_order(),
+            Err(GraphError::Cycle(_))
+        ));
+        assert_eq!(3, graph.containers_of("a").unwrap().len());
+
+        let graph = BagGraph::parse(
+            "a bags contain 18446744073709551615 b bags.\nb bags contain 1 c bag.\n".as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            Err(GraphError::Overflow(String::from("a"))),
+            graph.contents_count("a")
+        );
+        assert_eq!(1, graph.contents_count("b").unwrap());
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error as ThisError;
+
+use crate::parse::{self, ParseResult};
+use crate::solver::{Result, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_instructions(input.as_bytes())?)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Self::Output1> {
+        match run_instructions(instructions, RunState::default()) {
+            Ok(accumulator) | Err(RunError::DetectedLoop { accumulator, .. }) => Ok(accumulator),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Self::Output2> {
+        Ok(repair_instructions(instructions, RunState::default())?)
+    }
+}
+
+pub fn parse_instructions(reader: impl BufRead) -> ParseResult<Vec<Instruction>> {
+    parse::values(reader)
+}
+
+pub fn run_instructions(
+    instructions: &[Instruction],
+    mut state: RunState,
+) -> Result<i32, RunError> {
+    while let Some(instruction) = instructions.get(state.instruction_pointer) {
+        if !state
+            .executed_instructions
+            .insert(state.instruction_pointer)
+        {
+            return Err(RunError::DetectedLoop {
+                instruction_pointer: state.instruction_pointer,
+                accumulator: state.accumulator,
+            });
+        }
+
+        run_instruction(
+            instruction,
+            &mut state.accumulator,
+            &mut state.instruction_pointer,
+        )?;
+    }
+
+    Ok(state.accumulator)
+}
+
+pub fn repair_instructions(
+    instructions: &[Instruction],
+    mut state: RunState,
+) -> Result<i32, RunError> {
+    while let Some(instruction) = instructions.get(state.instruction_pointer) {
+        if !state
+            .executed_instructions
+            .insert(state.instruction_pointer)
+        {
+            return Err(RunError::DetectedLoop {
+                instruction_pointer: state.instruction_pointer,
+                accumulator: state.accumulator,
+            });
+        }
+
+        if matches!(instruction.operation, Operation::Jmp | Operation::Nop) {
+            // try original
+            let mut try_state = state.clone();
+            let original = run_instruction(
+                instruction,
+                &mut try_state.accumulator,
+                &mut try_state.instruction_pointer,
+            )
+            .and_then(|_| repair_instructions(instructions, try_state));
+
+            if let Ok(v) = original {
+                return Ok(v);
+            }
+
+            // try corrected
+            let mut try_state = state.clone();
+            let corrected_instruction = Instruction {
+                operation: match instruction.operation {
+                    Operation::Jmp => Operation::Nop,
+                    Operation::Nop => Operation::Jmp,
+                    Operation::Acc => unreachable!("Can't correct an acc instruction"),
+                },
+                ..*instruction
+            };
+
+            run_instruction(
+                &corrected_instruction,
+                &mut try_state.accumulator,
+                &mut try_state.instruction_pointer,
+            )?;
+
+            return run_instructions(instructions, try_state);
+        } else {
+            run_instruction(
+                instruction,
+                &mut state.accumulator,
+                &mut state.instruction_pointer,
+            )?;
+        }
+    }
+
+    Ok(state.accumulator)
+}
+
+pub fn run_instruction(
+    instruction: &Instruction,
+    acc: &mut i32,
+    instruction_pointer: &mut usize,
+) -> Result<(), RunError> {
+    let offset = match instruction.operation {
+        Operation::Acc => {
+            *acc = acc
+                .checked_add(instruction.argument)
+                .ok_or(RunError::AccumulatorOverflow {
+                    instruction_pointer: *instruction_pointer,
+                })?;
+
+            1
+        }
+        Operation::Jmp => instruction.argument,
+        Operation::Nop => 1,
+    };
+
+    *instruction_pointer = if offset.is_negative() {
+        instruction_pointer.checked_sub(offset.unsigned_abs() as usize)
+    } else {
+        instruction_pointer.checked_add(offset as usize)
+    }
+    .ok_or(RunError::JumpOutOfRange {
+        instruction_pointer: *instruction_pointer,
+        offset,
+    })?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RunState {
+    pub accumulator: i32,
+    pub instruction_pointer: usize,
+    pub executed_instructions: HashSet<usize>,
+}
+
+#[derive(ThisError, Debug)]
+pub enum RunError {
+    #[error("Detected instruction loop at pointer: {instruction_pointer}")]
+    DetectedLoop {
+        instruction_pointer: usize,
+        accumulator: i32,
+    },
+    #[error("Jump by {offset} at pointer {instruction_pointer} leaves the program")]
+    JumpOutOfRange {
+        instruction_pointer: usize,
+        offset: i32,
+    },
+    #[error("Accumulator overflow at pointer: {instruction_pointer}")]
+    AccumulatorOverflow { instruction_pointer: usize },
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub argument: i32,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.splitn(2, ' ');
+        let operation = split
+            .next()
+            .map(|s| s.parse::<Operation>())
+            .unwrap_or_else(|| {
+                Err(ParseError::MissingInstructionPart {
+                    instruction: String::from(s),
+                    missing_part: "operation",
+                })
+            })?;
+
+        let argument = split
+            .next()
+            .map(|s| s.parse::<i32>().map_err(|e| e.into()))
+            .unwrap_or_else(|| {
+                Err(ParseError::MissingInstructionPart {
+                    instruction: String::from(s),
+                    missing_part: "argument",
+                })
+            })?;
+
+        Ok(Self {
+            operation,
+            argument,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "acc" => Self::Acc,
+            "jmp" => Self::Jmp,
+            "nop" => Self::Nop,
+            _ => return Err(ParseError::UnknownOperation(String::from(s))),
+        })
+    }
+}
+
+#[derive(ThisError, Debug)]
+pub enum ParseError {
+    #[error("Missing part {missing_part} of instruction {instruction}")]
+    MissingInstructionPart {
+        instruction: String,
+        missing_part: &'static str,
+    },
+    #[error("Unknown operation {0}")]
+    UnknownOperation(String),
+    #[error("Failed to parse argument")]
+    InvalidArgument(#[from] ParseIntError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_instructions, repair_instructions, run_instructions, RunError, RunState};
+
+    #[test]
+    fn test_invalid_programs() {
+        let instructions = parse_instructions("jmp -5".as_bytes()).unwrap();
+        let error = run_instructions(&instructions, RunState::default()).unwrap_err();
+        assert!(matches!(error, RunError::JumpOutOfRange { offset: -5, .. }));
+        assert_eq!(
+            0,
+            repair_instructions(&instructions, RunState::default()).unwrap()
+        );
+
+        let instructions = parse_instructions("acc +2147483647\nacc +1".as_bytes()).unwrap();
+        let error = run_instructions(&instructions, RunState::default()).unwrap_err();
+        assert!(matches!(
+            error,
+            RunError::AccumulatorOverflow {
+                instruction_pointer: 1
+            }
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use crate::parse::{self, ParseError, ParseResult};
+use crate::solver::{Result, Solution};
+
+/// Number of preceding numbers a number has to be the sum of two of
+pub const PREAMBLE_LENGTH: usize = 25;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = (usize, Vec<u64>);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input.as_bytes())?)
+    }
+
+    fn part1((preamble_length, numbers): &Self::Input) -> Result<Self::Output1> {
+        Ok(find_invalid_number(numbers, *preamble_length).ok_or("Every number is valid")?)
+    }
+
+    fn part2((preamble_length, numbers): &Self::Input) -> Result<Self::Output2> {
+        Ok(find_weakness(numbers, *preamble_length).ok_or("Couldn't find encryption weakness")?)
+    }
+}
+
+/// Reads one number per line, an optional first line `preamble: <length>` overrides the
+/// preamble length of 25
+pub fn parse(reader: impl BufRead) -> ParseResult<(usize, Vec<u64>)> {
+    let (mut preamble_length, mut numbers) = (None, Vec::new());
+
+    for line in parse::lines(reader) {
+        let (idx, line) = line?;
+        let value = line.trim();
+
+        match value.strip_prefix("preamble:") {
+            _ if value.is_empty() => {}
+            Some(length) if preamble_length.is_none() && numbers.is_empty() => {
+                let length = length.trim();
+                preamble_length = Some(
+                    length
+                        .parse()
+                        .map_err(|e| ParseError::new(idx, &line, length, e))?,
+                );
+            }
+            _ => numbers.push(
+                value
+                    .parse()
+                    .map_err(|e| ParseError::new(idx, &line, value, e))?,
+            ),
+        }
+    }
+
+    Ok((preamble_length.unwrap_or(PREAMBLE_LENGTH), numbers))
+}
+
+/// The encryption weakness derived from the first invalid number
+pub fn find_weakness(numbers: &[u64], preamble_length: usize) -> Option<u64> {
+    find_encryption_weakness(numbers, find_invalid_number(numbers, preamble_length)?)
+}
+
+pub fn calculate_permutation_sums(numbers: &[u64]) -> HashMap<u64, HashSet<(usize, usize)>> {
+    let mut sums = HashMap::new();
+
+    for (idx_a, v_a) in numbers.iter().enumerate() {
+        for (idx_b, v_b) in numbers.iter().enumerate() {
+            // skip same values
+            if v_a == v_b {
+                continue;
+            }
+
+            let sum = v_a + v_b;
+            sums.entry(sum)
+                .or_insert_with(HashSet::new)
+                .insert((idx_a, idx_b));
+        }
+    }
+
+    sums
+}
+
+pub fn is_number_valid(
+    num: u64,
+    first_valid_index: usize,
+    sums: &HashMap<u64, HashSet<(usize, usize)>>,
+) -> bool {
+    sums.get(&num)
+        .map(|sources| {
+            let valid_range = first_valid_index..;
+            sources
+                .iter()
+                .any(|indices| valid_range.contains(&indices.0) && valid_range.contains(&indices.1))
+        })
+        .unwrap_or(false)
+}
+
+/// The first number that isn't the sum of two of the `preamble_length` numbers before it
+pub fn find_invalid_number(numbers: &[u64], preamble_length: usize) -> Option<u64> {
+    for idx_num in preamble_length..numbers.len() {
+        let num = numbers[idx_num];
+        let mut number_is_valid = false;
+
+        for idx_a in (idx_num - preamble_length)..idx_num {
+            let a = numbers[idx_a];
+
+            if numbers[(idx_a + 1)..idx_num].iter().any(|b| a + b == num) {
+                number_is_valid = true;
+                break;
+            }
+        }
+
+        if !number_is_valid {
+            return Some(num);
+        }
+    }
+
+    None
+}
+
+pub fn find_encryption_weakness(numbers: &[u64], invalid_number: u64) -> Option<u64> {
+    for (idx_a, &v_a) in numbers.iter().enumerate() {
+        let mut current_sum = v_a;
+        let mut sum_operands = vec![v_a];
+
+        for v_b in numbers[idx_a + 1..].iter() {
+            current_sum += *v_b;
+
+            match current_sum.cmp(&invalid_number) {
+                Ordering::Less => {
+                    sum_operands.push(*v_b);
+                }
+                Ordering::Greater => break,
+                Ordering::Equal => {
+                    sum_operands.sort_unstable();
+                    return Some(sum_operands.first().unwrap() + sum_operands.last().unwrap());
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,188 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error as ThisError;
+
+use crate::answers::{Answers, AnswersError};
+use crate::solver::{find_solver, Part};
+
+/// A puzzle example stored as `<dir>/dayN/<name>.txt`, the expected answers are read from
+/// the `[<name>]` table of `<dir>/dayN/answers.toml`
+#[derive(Debug)]
+pub struct Example {
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+}
+
+#[derive(ThisError, Debug)]
+pub enum ExampleError {
+    #[error("Failed to read examples: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid answers for the examples in {}: {source}", path.display())]
+    Answers { path: PathBuf, source: AnswersError },
+    #[error("Example {} has no expected answers", path.display())]
+    MissingAnswers { path: PathBuf },
+    #[error("Answers for {name} in {} have no example input", path.display())]
+    MissingInput { path: PathBuf, name: String },
+}
+
+/// The outcome of running a solver on an example that didn't produce the expected answer
+#[derive(Debug)]
+pub struct Mismatch {
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Example {
+    /// Runs the solvers of every part with an expected answer and returns the mismatches
+    pub fn check(&self) -> Vec<Mismatch> {
+        self.expected
+            .iter()
+            .filter_map(|(part, expected)| {
+                let actual = match find_solver(self.day, *part) {
+                    Some(solver) => solver
+                        .solve(&self.input)
+                        .map(|answer| answer.to_string())
+                        .unwrap_or_else(|e| format!("error: {}", e)),
+                    None => String::from("no solver"),
+                };
+
+                if actual == *expected {
+                    None
+                } else {
+                    Some(Mismatch {
+                        part: *part,
+                        expected: expected.clone(),
+                        actual,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// Collects the examples of all `dayN` directories within `dir`, ordered by day and name
+pub fn discover(dir: impl AsRef<Path>) -> Result<Vec<Example>, ExampleError> {
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let day = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse::<u8>().ok());
+
+        if let (Some(day), true) = (day, path.is_dir()) {
+            examples.extend(discover_day(day, &path)?);
+        }
+    }
+
+    examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+
+    Ok(examples)
+}
+
+fn discover_day(day: u8, dir: &Path) -> Result<Vec<Example>, ExampleError> {
+    let answers_path = dir.join("answers.toml");
+    let answers = Answers::load(&answers_path).map_err(|source| ExampleError::Answers {
+        path: answers_path.clone(),
+        source,
+    })?;
+
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().map_or(true, |ext| ext != "txt") {
+            continue;
+        }
+
+        let name = path
+            .file_stem()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let expected = [Part::A, Part::B]
+            .iter()
+            .filter_map(|part| answers.get_named(&name, *part).map(|a| (*part, a)))
+            .collect::<Vec<_>>();
+
+        if expected.is_empty() {
+            return Err(ExampleError::MissingAnswers { path });
+        }
+
+        examples.push(Example {
+            day,
+            name,
+            input: fs::read_to_string(&path)?,
+            path,
+            expected,
+        });
+    }
+
+    if let Some(name) = answers
+        .names()
+        .find(|name| !examples.iter().any(|e| e.name == *name))
+    {
+        return Err(ExampleError::MissingInput {
+            path: answers_path,
+            name: String::from(name),
+        });
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::discover;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[test]
+    fn test_examples() {
+        let examples = discover(concat!(env!("CARGO_MANIFEST_DIR"), "/examples")).unwrap();
+        assert!(!examples.is_empty());
+
+        // some examples run for a few seconds, so they are spread over a few worker threads,
+        // more would multiply the memory of the day 15 examples
+        let workers = thread::available_parallelism().map_or(1, |n| n.get().min(4));
+        let queue = Arc::new(Mutex::new(examples.into_iter()));
+        let handles = (0..workers)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+
+                thread::spawn(move || {
+                    let next = || queue.lock().unwrap().next();
+                    let mut results = Vec::new();
+                    while let Some(example) = next() {
+                        results.push((example.check(), example));
+                    }
+
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let failures = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .flat_map(|(mismatches, example)| {
+                mismatches.into_iter().map(move |m| {
+                    format!(
+                        "{} part {}: expected {}, got {}",
+                        example.path.display(),
+                        m.part,
+                        m.expected,
+                        m.actual
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::io::BufRead;
+use std::ops::{Add, AddAssign};
+
+use crate::parse::{self, ParseError, ParseResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+/// The cells considered neighbours of a cell
+#[derive(Debug, Clone, Copy)]
+pub enum Neighbourhood {
+    /// The four horizontally and vertically adjacent cells
+    Four,
+    /// The eight horizontally, vertically and diagonally adjacent cells
+    Eight,
+}
+
+impl Neighbourhood {
+    pub fn directions(&self) -> &'static [Point] {
+        const FOUR: [Point; 4] = [
+            Point::new(0, -1),
+            Point::new(-1, 0),
+            Point::new(1, 0),
+            Point::new(0, 1),
+        ];
+
+        const EIGHT: [Point; 8] = [
+            Point::new(-1, -1),
+            Point::new(0, -1),
+            Point::new(1, -1),
+            Point::new(-1, 0),
+            Point::new(1, 0),
+            Point::new(-1, 1),
+            Point::new(0, 1),
+            Point::new(1, 1),
+        ];
+
+        match self {
+            Self::Four => &FOUR,
+            Self::Eight => &EIGHT,
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row major order
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            cells.len().checked_rem(width).unwrap_or(cells.len()) == 0,
+            "cell count {} is not a multiple of the width {}",
+            cells.len(),
+            width
+        );
+
+        Self {
+            width,
+            height: cells.len().checked_div(width).unwrap_or_default(),
+            cells,
+        }
+    }
+
+    /// Parses one row per non-empty line, every char is mapped to a cell by `cell`
+    pub fn parse<E>(reader: impl BufRead, cell: impl Fn(char) -> Result<T, E>) -> ParseResult<Self>
+    where
+        E: fmt::Display,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for line in parse::lines(reader) {
+            let (idx, line) = line?;
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+
+            for (char_idx, c) in row.char_indices() {
+                cells.push(cell(c).map_err(|e| {
+                    ParseError::new(idx, &line, &row[char_idx..char_idx + c.len_utf8()], e)
+                })?);
+            }
+
+            let length = row.chars().count();
+            match width {
+                None => width = Some(length),
+                Some(expected) if expected != length => {
+                    return Err(ParseError::new(
+                        idx,
+                        &line,
+                        row,
+                        format!("Expected a line length of {}", expected),
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index(pos).map(move |idx| &mut self.cells[idx])
+    }
+
+    /// Looks up a cell as if the grid repeated endlessly in every direction
+    pub fn get_wrapping(&self, pos: Point) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        self.get(Point {
+            x: pos.x.rem_euclid(self.width as i32),
+            y: pos.y.rem_euclid(self.height as i32),
+        })
+    }
+
+    fn index(&self, pos: Point) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over all cells together with their position in row major order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+
+        self.cells.iter().enumerate().map(move |(idx, cell)| {
+            (
+                Point {
+                    x: (idx % width) as i32,
+                    y: (idx / width) as i32,
+                },
+                cell,
+            )
+        })
+    }
+
+    /// Iterates over the neighbouring cells of `pos` that lie within the grid
+    pub fn neighbours(
+        &self,
+        pos: Point,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        neighbourhood
+            .directions()
+            .iter()
+            .filter_map(move |direction| {
+                let neighbour = pos + *direction;
+
+                self.get(neighbour).map(|cell| (neighbour, cell))
+            })
+    }
+
+    /// Iterates over the cells visited by repeatedly stepping from `pos` into `direction`,
+    /// stops at the edge of the grid
+    pub fn ray(&self, pos: Point, direction: Point) -> impl Iterator<Item = (Point, &T)> {
+        let mut current = pos;
+
+        std::iter::from_fn(move || {
+            current += direction;
+
+            self.get(current).map(|cell| (current, cell))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, Neighbourhood, Point};
+
+    const EXAMPLE: &str = r#"
+        123
+        456
+    "#;
+
+    fn example() -> Grid<u32> {
+        Grid::parse(EXAMPLE.as_bytes(), |c| {
+            c.to_digit(10).ok_or("Expected a digit")
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_accessors() {
+        let grid = example();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+        assert_eq!(Some(&1), grid.get_wrapping(Point::new(3, 2)));
+        assert_eq!(Some(&6), grid.get_wrapping(Point::new(-1, -1)));
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = example();
+
+        let neighbours = |n| {
+            grid.neighbours(Point::new(0, 0), n)
+                .map(|(_, c)| *c)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![2, 4], neighbours(Neighbourhood::Four));
+        assert_eq!(vec![2, 4, 5], neighbours(Neighbourhood::Eight));
+
+        let ray = grid
+            .ray(Point::new(-1, 0), Point::new(1, 0))
+            .map(|(_, c)| *c)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], ray);
+
+        assert_eq!(Some(&[4, 5, 6][..]), grid.row(1));
+        assert_eq!(vec![3, 6], grid.column(2).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!("123\n456\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Grid::parse("12\n3x".as_bytes(), |c| {
+            c.to_digit(10).ok_or("Expected a digit")
+        })
+        .unwrap_err();
+
+        assert_eq!((2, 2), (error.line, error.column));
+        assert_eq!("x", error.text);
+    }
+}
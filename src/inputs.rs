@@ -0,0 +1,177 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error as ThisError;
+
+pub mod mock;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2020";
+
+/// Source of puzzle inputs that are missing from the cache
+pub trait Fetch {
+    fn fetch(&self, day: u8) -> Result<String, InputError>;
+}
+
+/// Downloads puzzle inputs from `<base_url>/day/<day>/input` using a session token
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+}
+
+impl Fetch for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, InputError> {
+        let url = format!("{}/day/{}/input", self.base_url.trim_end_matches('/'), day);
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => InputError::Status { day, status },
+                e => InputError::Request(e.to_string()),
+            })?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+/// Resolves puzzle inputs to dayN.txt files in a cache directory, missing files are fetched
+/// if a fetcher is configured
+pub struct InputProvider {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetch>>,
+}
+
+impl InputProvider {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(self, fetcher: impl Fetch + 'static) -> Self {
+        Self {
+            fetcher: Some(Box::new(fetcher)),
+            ..self
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}.txt", day))
+    }
+
+    /// Returns the path of the cached input, fetching it first if it is missing
+    pub fn ensure(&self, day: u8) -> Result<PathBuf, InputError> {
+        let path = self.path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| InputError::Missing {
+            day,
+            path: path.clone(),
+        })?;
+
+        let input = fetcher.fetch(day)?;
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&path, input)?;
+
+        Ok(path)
+    }
+
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        Ok(fs::read_to_string(self.ensure(day)?)?)
+    }
+}
+
+#[derive(ThisError, Debug)]
+pub enum InputError {
+    #[error("Missing input for day {day} at {}, set a session token to download it", path.display())]
+    Missing { day: u8, path: PathBuf },
+    #[error("Failed to download the input for day {day}, server responded with {status}")]
+    Status { day: u8, status: u16 },
+    #[error("Failed to download input: {0}")]
+    Request(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockServer;
+    use super::{HttpFetcher, InputError, InputProvider};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2020-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn test_cached_input() {
+        let dir = cache_dir("cached");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1721\n979\n").unwrap();
+
+        let server = MockServer::start(vec![]).unwrap();
+        let provider = InputProvider::new(&dir).with_fetcher(HttpFetcher::new(server.url(), "s"));
+
+        assert_eq!("1721\n979\n", provider.get(1).unwrap());
+        assert!(server.requests().is_empty());
+        assert!(matches!(
+            InputProvider::new(&dir).get(2),
+            Err(InputError::Missing { day: 2, .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_missing_input() {
+        let dir = cache_dir("fetch");
+        let server = MockServer::start(vec![
+            ("/2020/day/15/input", 200, "0,3,6\n"),
+            ("/2020/day/16/input", 404, "Not Found"),
+        ])
+        .unwrap();
+
+        let provider = InputProvider::new(&dir)
+            .with_fetcher(HttpFetcher::new(format!("{}/2020", server.url()), "secret"));
+
+        assert_eq!("0,3,6\n", provider.get(15).unwrap());
+        assert_eq!(
+            "0,3,6\n",
+            fs::read_to_string(dir.join("day15.txt")).unwrap()
+        );
+        assert!(matches!(
+            provider.get(16),
+            Err(InputError::Status {
+                day: 16,
+                status: 404
+            })
+        ));
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("/2020/day/15/input", requests[0].path);
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
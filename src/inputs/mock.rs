@@ -0,0 +1,144 @@
+//! In-process stand-in for the puzzle input server, serves canned responses over plain HTTP
+//! on a local port and records every request it receives
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl RecordedRequest {
+    /// Looks up a header value, header names are compared case insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+type Responses = Vec<(String, u16, String)>;
+
+pub struct MockServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Starts serving the given `(path, status, body)` responses, unknown paths receive a 404
+    pub fn start(responses: Vec<(&str, u16, &str)>) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+
+        let responses: Responses = responses
+            .into_iter()
+            .map(|(path, status, body)| (String::from(path), status, String::from(body)))
+            .collect();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = thread::spawn({
+            let requests = Arc::clone(&requests);
+            let shutdown = Arc::clone(&shutdown);
+
+            move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    if let Ok(stream) = stream {
+                        let _ = handle_connection(stream, &responses, &requests);
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            addr,
+            requests,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+
+        // wake up the blocking accept so the server thread notices the shutdown
+        let _ = TcpStream::connect(self.addr);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    responses: &Responses,
+    requests: &Mutex<Vec<RecordedRequest>>,
+) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = String::from(parts.next().unwrap_or_default());
+    let path = String::from(parts.next().unwrap_or_default());
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((String::from(name.trim()), String::from(value.trim())));
+        }
+    }
+
+    let (status, body) = responses
+        .iter()
+        .find(|(p, _, _)| *p == path)
+        .map_or((404, "Not Found"), |(_, status, body)| {
+            (*status, body.as_str())
+        });
+
+    requests.lock().unwrap().push(RecordedRequest {
+        method,
+        path,
+        headers,
+    });
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        if status < 400 { "OK" } else { "Error" },
+        body.len(),
+        body
+    )?;
+
+    stream.flush()
+}
@@ -0,0 +1,737 @@
+use clap::Clap;
+use serde::Serialize;
+use std::env;
+use std::error::Error as StdError;
+use std::fs;
+use std::io::{self, Read};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc2020::answers::Answers;
+use aoc2020::bench::{Report, Stats};
+use aoc2020::day2;
+use aoc2020::day3::TreeMap;
+use aoc2020::day4::{self, schema::Schema, ValidationReport};
+use aoc2020::day5::{self, boarding_pass::PlaneLayout, SeatMap};
+use aoc2020::day6::{self, Query};
+use aoc2020::grid::Point;
+use aoc2020::inputs::{HttpFetcher, InputProvider, DEFAULT_BASE_URL};
+use aoc2020::parse::ParseError;
+use aoc2020::solver::{find_solver, Part, Solver, BENCHMARKS, SOLVERS};
+use aoc2020::{Answer, Details};
+
+#[derive(Clap)]
+struct Opts {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Clap)]
+enum Command {
+    /// Run a single solver or all of them
+    Run(RunOpts),
+    /// Benchmark the parse, part1 and part2 phases of the solvers
+    Bench(BenchOpts),
+    /// Check the day 2 passwords against a policy expression
+    Policy(PolicyOpts),
+    /// Rank the day 3 slopes by the number of trees hit, render their paths or find the route
+    /// hitting the fewest trees
+    Slopes(SlopesOpts),
+    /// Check the day 4 passports against a schema
+    Passports(PassportsOpts),
+    /// Draw the day 5 seat map, marking occupied, free and your seat
+    Seats(SeatsOpts),
+    /// Count the day 6 customs answers selected by a set query
+    Customs(CustomsOpts),
+}
+
+#[derive(Clap)]
+struct RunOpts {
+    /// Run every registered solver on its puzzle input
+    #[clap(long, conflicts_with_all = &["day", "part", "input", "inline"])]
+    all: bool,
+    /// Run every registered solver and compare the results against the known answers
+    #[clap(long, conflicts_with_all = &["all", "day", "part", "input", "inline"])]
+    verify: bool,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// File containing the known answers used by --verify
+    #[clap(long, default_value = "answers.toml")]
+    answers: String,
+    #[clap(required_unless_present_any = &["all", "verify"])]
+    day: Option<u8>,
+    #[clap(required_unless_present_any = &["all", "verify"])]
+    part: Option<Part>,
+    /// Puzzle input file or - for stdin, defaults to dayN.txt in the inputs directory
+    #[clap(conflicts_with = "inline")]
+    input: Option<String>,
+    /// Puzzle input given directly on the command line, \n separates lines
+    #[clap(long)]
+    inline: Option<String>,
+    /// Output format of the solver results, text or json
+    #[clap(long, default_value = "text", conflicts_with = "verify")]
+    format: Format,
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "text" => Self::Text,
+            "json" => Self::Json,
+            s => return Err(format!("Unknown format {}, expected text or json", s)),
+        })
+    }
+}
+
+/// A solver result as printed with --format json
+#[derive(Serialize)]
+struct RunOutput<'a> {
+    day: u8,
+    part: String,
+    answer: &'a Answer,
+    timings: RunTimings,
+    details: &'a Details,
+}
+
+#[derive(Serialize)]
+struct RunTimings {
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+/// Where missing puzzle inputs are looked up
+#[derive(Clap)]
+struct InputOpts {
+    /// Directory containing the dayN.txt puzzle inputs
+    #[clap(long, default_value = "inputs")]
+    inputs_dir: String,
+    /// Server missing puzzle inputs are downloaded from when AOC_SESSION is set
+    #[clap(long, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl InputOpts {
+    /// Inputs are read from the inputs directory, missing ones are downloaded if the
+    /// AOC_SESSION environment variable holds a session token
+    fn provider(&self) -> InputProvider {
+        let provider = InputProvider::new(&self.inputs_dir);
+
+        match env::var("AOC_SESSION") {
+            Ok(session) if !session.trim().is_empty() => {
+                provider.with_fetcher(HttpFetcher::new(&self.base_url, session.trim()))
+            }
+            _ => provider,
+        }
+    }
+
+    /// The given input file, stdin for - or the default input of the day
+    fn resolve(&self, path: Option<String>, day: u8) -> Result<Input, Box<dyn StdError>> {
+        match path {
+            Some(path) if path == "-" => Ok(Input::Stdin),
+            Some(path) => Ok(Input::File(path)),
+            None => default_input(&self.provider(), day),
+        }
+    }
+}
+
+/// Where the puzzle input of a single solver run is read from
+enum Input {
+    File(String),
+    Stdin,
+    Inline(String),
+}
+
+impl Input {
+    fn read(&self) -> io::Result<String> {
+        match self {
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+
+                Ok(input)
+            }
+            Self::Inline(input) => Ok(input.replace("\\n", "\n")),
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::File(path) => path,
+            Self::Stdin => "<stdin>",
+            Self::Inline(_) => "<inline>",
+        }
+    }
+}
+
+#[derive(Clap)]
+struct BenchOpts {
+    /// Number of times every solver is run
+    #[clap(long, default_value = "10")]
+    iterations: NonZeroUsize,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// Print the report as JSON
+    #[clap(long)]
+    json: bool,
+    /// Previously saved JSON report to compare the median timings against
+    #[clap(long, conflicts_with = "json")]
+    baseline: Option<String>,
+    /// Only benchmark the given day
+    day: Option<u8>,
+}
+
+#[derive(Clap)]
+struct PolicyOpts {
+    /// Policy expression built from occurrences, positions, length(min, max),
+    /// class(name, min[, max]) and regex("pattern") with all(..), any(..) and not(..),
+    /// length, class and regex without arguments check the rules of each input line
+    #[clap(long, default_value = "occurrences")]
+    policy: String,
+    /// List every line failing the policy together with the reason and every line that
+    /// can't be parsed instead of stopping at the first one
+    #[clap(long)]
+    report: bool,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// Puzzle input file or - for stdin, defaults to day2.txt in the inputs directory
+    input: Option<String>,
+}
+
+#[derive(Clap)]
+struct SlopesOpts {
+    /// Horizontal steps to evaluate, a single number or an inclusive range like -3..=7
+    #[clap(long, default_value = "1..=7", allow_hyphen_values = true)]
+    dx: StepRange,
+    /// Vertical steps to evaluate, a single number or an inclusive range like 1..=2
+    #[clap(long, default_value = "1..=2")]
+    dy: StepRange,
+    /// Print the map with the paths of the given slopes instead of ranking slopes
+    #[clap(long)]
+    render: bool,
+    /// Find the route from the top to the bottom row hitting the fewest trees, every
+    /// combination of the dx and dy steps is an allowed move
+    #[clap(long, conflicts_with = "render")]
+    route: bool,
+    /// Slope to render as dx,dy, can be repeated to overlay several paths, defaults to 3,1
+    #[clap(
+        long = "slope",
+        requires = "render",
+        allow_hyphen_values = true,
+        multiple_occurrences = true,
+        number_of_values = 1
+    )]
+    slopes: Vec<Slope>,
+    /// Colour the rendered paths using ANSI escape codes
+    #[clap(long, requires = "render")]
+    color: bool,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// Puzzle input file or - for stdin, defaults to day3.txt in the inputs directory
+    input: Option<String>,
+}
+
+#[derive(Clap)]
+struct PassportsOpts {
+    /// TOML or JSON file describing the passport fields, defaults to the puzzle rules
+    #[clap(long)]
+    schema: Option<String>,
+    /// List every missing and invalid field of each record and the fields failing most often
+    #[clap(long)]
+    report: bool,
+    /// Output format of the report, text or json
+    #[clap(long, default_value = "text", requires = "report")]
+    format: Format,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// Puzzle input file or - for stdin, defaults to day4.txt in the inputs directory
+    input: Option<String>,
+}
+
+#[derive(Clap)]
+struct SeatsOpts {
+    /// Number of rows of the plane
+    #[clap(long, default_value = "128")]
+    rows: u32,
+    /// Number of seats per row
+    #[clap(long, default_value = "8")]
+    columns: u32,
+    /// Write the seat map as an SVG image to the given file instead of printing it
+    #[clap(long)]
+    svg: Option<String>,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// Puzzle input file or - for stdin, defaults to day5.txt in the inputs directory
+    input: Option<String>,
+}
+
+#[derive(Clap)]
+struct CustomsOpts {
+    /// Questions counted per group: anyone, everyone, exactly-one or at-least=K
+    #[clap(long, default_value = "anyone")]
+    query: Query,
+    /// Print the people, selected questions and their count for every group
+    #[clap(long)]
+    groups: bool,
+    /// Print how many people answered each question with yes
+    #[clap(long)]
+    frequencies: bool,
+    #[clap(flatten)]
+    inputs: InputOpts,
+    /// Puzzle input file or - for stdin, defaults to day6.txt in the inputs directory
+    input: Option<String>,
+}
+
+/// An inclusive range of slope steps
+struct StepRange(RangeInclusive<i32>);
+
+impl FromStr for StepRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let step = |s: &str| {
+            s.trim()
+                .parse::<i32>()
+                .map_err(|e| format!("Invalid step {:?}: {}", s, e))
+        };
+
+        Ok(Self(match s.split_once("..=") {
+            Some((start, end)) => step(start)?..=step(end)?,
+            None => step(s)?..=step(s)?,
+        }))
+    }
+}
+
+/// A day 3 slope given as dx,dy
+struct Slope(Point);
+
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| format!("Expected a slope like 3,1, got {:?}", s))?;
+        let step = |s: &str| {
+            s.trim()
+                .parse::<i32>()
+                .map_err(|e| format!("Invalid step {:?}: {}", s, e))
+        };
+
+        let slope = Point::new(step(x)?, step(y)?);
+        if slope.y <= 0 {
+            return Err(format!("Slope {} doesn't move downwards", s));
+        }
+
+        Ok(Self(slope))
+    }
+}
+
+fn main() {
+    let opts = Opts::parse();
+
+    let result = match opts.command {
+        Command::Run(opts) => run(opts),
+        Command::Bench(opts) => bench(opts),
+        Command::Policy(opts) => check_policy(opts),
+        Command::Slopes(opts) => rank_slopes(opts),
+        Command::Passports(opts) => check_passports(opts),
+        Command::Seats(opts) => draw_seats(opts),
+        Command::Customs(opts) => query_customs(opts),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(opts: RunOpts) -> Result<(), Box<dyn StdError>> {
+    let inputs = opts.inputs.provider();
+
+    if opts.verify {
+        return verify(&inputs, Answers::load(&opts.answers)?);
+    }
+
+    if opts.all {
+        for solver in SOLVERS {
+            run_solver(solver, &default_input(&inputs, solver.day)?, opts.format)?;
+        }
+
+        return Ok(());
+    }
+
+    let (day, part) = (opts.day.unwrap(), opts.part.unwrap());
+    let solver =
+        find_solver(day, part).ok_or_else(|| format!("No solver for day {}{}", day, part))?;
+
+    let input = match opts.inline {
+        Some(inline) => Input::Inline(inline),
+        None => opts.inputs.resolve(opts.input, day)?,
+    };
+
+    run_solver(solver, &input, opts.format)
+}
+
+fn run_solver(solver: &Solver, input: &Input, format: Format) -> Result<(), Box<dyn StdError>> {
+    let name = input.name();
+    let input = input.read()?;
+
+    let solved = solver.run(&input).map_err(|e| locate_in_file(e, name))?;
+
+    match format {
+        Format::Text => {
+            println!(
+                "day{}{}: {} | elapsed: {:?}",
+                solver.day,
+                solver.part,
+                solved.answer,
+                solved.parse_time + solved.solve_time
+            );
+
+            for (key, value) in solved.details.iter() {
+                println!("  {}: {}", key, value);
+            }
+        }
+        Format::Json => {
+            let output = RunOutput {
+                day: solver.day,
+                part: solver.part.to_string(),
+                answer: &solved.answer,
+                timings: RunTimings {
+                    parse_ns: solved.parse_time.as_nanos() as u64,
+                    solve_ns: solved.solve_time.as_nanos() as u64,
+                },
+                details: &solved.details,
+            };
+
+            println!("{}", serde_json::to_string(&output)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(inputs: &InputProvider, answers: Answers) -> Result<(), Box<dyn StdError>> {
+    let mut mismatches = Vec::new();
+
+    for solver in SOLVERS {
+        let expected = answers.get(solver.day, solver.part);
+        let path = inputs.path(solver.day).display().to_string();
+        let actual = inputs
+            .get(solver.day)
+            .map_err(Into::into)
+            .and_then(|input| solver.solve(&input))
+            .map_err(|e| locate_in_file(e, &path))
+            .map(|answer| answer.to_string())
+            .unwrap_or_else(|e| format!("error: {}", e));
+
+        let status = if expected.as_ref() == Some(&actual) {
+            "ok"
+        } else {
+            mismatches.push((solver, expected, actual));
+            "FAILED"
+        };
+
+        println!("day{}{}: {}", solver.day, solver.part, status);
+    }
+
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    println!("{:<8} {:<24} actual", "solver", "expected");
+    for (solver, expected, actual) in mismatches.iter() {
+        println!(
+            "{:<8} {:<24} {}",
+            format!("day{}{}", solver.day, solver.part),
+            expected.as_deref().unwrap_or("missing"),
+            actual
+        );
+    }
+
+    Err(format!(
+        "{} solver(s) did not match the known answers",
+        mismatches.len()
+    )
+    .into())
+}
+
+fn bench(opts: BenchOpts) -> Result<(), Box<dyn StdError>> {
+    let baseline = match &opts.baseline {
+        Some(path) => Some(serde_json::from_str::<Report>(&fs::read_to_string(path)?)?),
+        None => None,
+    };
+
+    let inputs = opts.inputs.provider();
+    let mut report = Report {
+        iterations: opts.iterations.get(),
+        days: Vec::new(),
+    };
+
+    for benchmark in BENCHMARKS
+        .iter()
+        .filter(|b| opts.day.map_or(true, |day| b.day == day))
+    {
+        let path = inputs.ensure(benchmark.day)?.display().to_string();
+        let input = fs::read_to_string(&path)?;
+        report.days.push(
+            benchmark
+                .run(&input, opts.iterations)
+                .map_err(|e| locate_in_file(e, &path))?,
+        );
+    }
+
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    let mut header = format!(
+        "{:<6} {:<6} {:>14} {:>14} {:>14}",
+        "day", "phase", "min", "median", "max"
+    );
+    if baseline.is_some() {
+        header.push_str(&format!(" {:>10}", "change"));
+    }
+    println!("{}", header);
+    for day in report.days.iter() {
+        let baseline_day = baseline.as_ref().and_then(|b| b.day(day.day));
+        let phases = [
+            ("parse", day.parse, baseline_day.map(|b| b.parse)),
+            ("part1", day.part1, baseline_day.map(|b| b.part1)),
+            ("part2", day.part2, baseline_day.map(|b| b.part2)),
+        ];
+
+        for (phase, stats, baseline_stats) in phases.iter() {
+            let mut row = format!(
+                "{:<6} {:<6} {:>14} {:>14} {:>14}",
+                format!("day{}", day.day),
+                phase,
+                format!("{:?}", Duration::from_nanos(stats.min_ns)),
+                format!("{:?}", Duration::from_nanos(stats.median_ns)),
+                format!("{:?}", Duration::from_nanos(stats.max_ns)),
+            );
+            if baseline.is_some() {
+                let change = baseline_stats
+                    .map_or_else(|| String::from("n/a"), |b| median_change(&b, stats));
+                row.push_str(&format!(" {:>10}", change));
+            }
+            println!("{}", row);
+        }
+    }
+
+    Ok(())
+}
+
+fn check_policy(opts: PolicyOpts) -> Result<(), Box<dyn StdError>> {
+    let policy = day2::policy::parse(&opts.policy)?;
+    let input = opts.inputs.resolve(opts.input, 2)?;
+
+    let (lines, errors) = day2::parse_all(input.read()?.as_bytes());
+    if !opts.report {
+        if let Some(error) = errors.first() {
+            return Err(error.clone().in_file(input.name()).into());
+        }
+    }
+
+    println!("{}: {} valid", policy, day2::count_valid(&lines, &*policy));
+
+    if opts.report {
+        for failure in day2::report(&lines, &*policy) {
+            println!(
+                "line {} ({}): {}",
+                failure.line.line, failure.line, failure.reason
+            );
+        }
+
+        for error in errors.iter() {
+            println!(
+                "line {}: unparseable: {}",
+                error.line,
+                error.clone().in_file(input.name())
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn rank_slopes(opts: SlopesOpts) -> Result<(), Box<dyn StdError>> {
+    let input = opts.inputs.resolve(opts.input, 3)?;
+
+    let map = TreeMap::parse(input.read()?.as_bytes()).map_err(|e| e.in_file(input.name()))?;
+
+    if opts.render {
+        let mut slopes = opts.slopes.iter().map(|s| s.0).collect::<Vec<_>>();
+        if slopes.is_empty() {
+            slopes.push(Point::new(3, 1));
+        }
+
+        print!("{}", map.render(&slopes, opts.color));
+        return Ok(());
+    }
+
+    if opts.route {
+        let dx = opts.dx.0;
+        let moves = opts
+            .dy
+            .0
+            .flat_map(|y| dx.clone().map(move |x| Point::new(x, y)))
+            .filter(|step| *step != Point::default())
+            .collect::<Vec<_>>();
+        let route = map
+            .fewest_trees_route(&moves)
+            .ok_or("The bottom row can't be reached with the given moves")?;
+
+        println!("trees: {}", route.trees);
+        for pos in route.path {
+            println!("{:>4} {:>4}", pos.x, pos.y);
+        }
+
+        return Ok(());
+    }
+
+    println!("{:>4} {:>4} trees", "dx", "dy");
+    for (slope, trees) in map.rank_slopes(opts.dx.0, opts.dy.0) {
+        println!("{:>4} {:>4} {}", slope.x, slope.y, trees);
+    }
+
+    Ok(())
+}
+
+fn check_passports(opts: PassportsOpts) -> Result<(), Box<dyn StdError>> {
+    let schema = match opts.schema {
+        Some(path) => Schema::load(&path).map_err(|e| format!("{}: {}", path, e))?,
+        None => Schema::passport(),
+    };
+    let input = opts.inputs.resolve(opts.input, 4)?;
+
+    let records =
+        day4::parse_records(input.read()?.as_bytes()).map_err(|e| e.in_file(input.name()))?;
+
+    if !opts.report {
+        println!("complete: {}", day4::count_valid(&records, &schema, false));
+        println!("valid: {}", day4::count_valid(&records, &schema, true));
+
+        return Ok(());
+    }
+
+    let report = ValidationReport::new(&records, &schema);
+    if let Format::Json = opts.format {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("{:>6}  {:<5}  error", "record", "field");
+    for record in report.records.iter() {
+        for error in record.errors.iter() {
+            println!("{:>6}  {:<5}  {}", record.record, error.field(), error);
+        }
+    }
+
+    println!();
+    println!("valid: {}", report.valid);
+    println!("invalid: {}", report.invalid);
+    println!();
+    println!("{:<5}  failures", "field");
+    for (field, count) in report.most_failed() {
+        println!("{:<5}  {}", field, count);
+    }
+
+    Ok(())
+}
+
+fn draw_seats(opts: SeatsOpts) -> Result<(), Box<dyn StdError>> {
+    let layout = PlaneLayout::new(opts.rows, opts.columns)?;
+    let input = opts.inputs.resolve(opts.input, 5)?;
+
+    let seats = day5::parse_seats_in(input.read()?.as_bytes(), layout)
+        .map_err(|e| e.in_file(input.name()))?;
+    let map = SeatMap::new(layout, &seats);
+
+    match opts.svg {
+        Some(path) => fs::write(path, map.render_svg())?,
+        None => print!("{}", map.render()),
+    }
+
+    Ok(())
+}
+
+fn query_customs(opts: CustomsOpts) -> Result<(), Box<dyn StdError>> {
+    let input = opts.inputs.resolve(opts.input, 6)?;
+
+    let groups =
+        day6::parse_groups(input.read()?.as_bytes()).map_err(|e| e.in_file(input.name()))?;
+
+    if opts.groups {
+        println!(
+            "{:>5} {:>5} {:>6} {:>5} questions",
+            "group", "line", "people", "count"
+        );
+        for (idx, group) in groups.iter().enumerate() {
+            let answers = opts.query.answers(group);
+            println!(
+                "{:>5} {:>5} {:>6} {:>5} {}",
+                idx + 1,
+                group.line_idx + 1,
+                group.people.len(),
+                answers.len(),
+                answers
+            );
+        }
+        println!();
+    }
+
+    if opts.frequencies {
+        let mut frequencies = [0; 26];
+        for group in groups.iter() {
+            for (total, frequency) in frequencies.iter_mut().zip(group.frequencies().iter()) {
+                *total += frequency;
+            }
+        }
+
+        for (question, frequency) in ('a'..='z').zip(frequencies.iter()) {
+            println!("{} {}", question, frequency);
+        }
+        println!();
+    }
+
+    println!("{}: {}", opts.query, opts.query.total(&groups));
+
+    Ok(())
+}
+
+fn median_change(baseline: &Stats, current: &Stats) -> String {
+    if baseline.median_ns == 0 {
+        return String::from("n/a");
+    }
+
+    let change = (current.median_ns as f64 / baseline.median_ns as f64 - 1.0) * 100.0;
+
+    format!("{:+.1}%", change)
+}
+
+/// Attaches the input file name to parse errors
+fn locate_in_file(error: Box<dyn StdError>, file: &str) -> Box<dyn StdError> {
+    match error.downcast::<ParseError>() {
+        Ok(e) => Box::new(e.in_file(file)),
+        Err(e) => e,
+    }
+}
+
+fn default_input(inputs: &InputProvider, day: u8) -> Result<Input, Box<dyn StdError>> {
+    Ok(Input::File(inputs.ensure(day)?.display().to_string()))
+}
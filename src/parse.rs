@@ -0,0 +1,220 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+use thiserror::Error as ThisError;
+
+pub type ParseResult<T> = std::result::Result<T, ParseError>;
+
+/// Malformed puzzle input, located by file, line and column
+#[derive(ThisError, Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.message
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, ", found {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ParseError {
+    /// Creates an error for `text` found in the line with the zero based index `line_idx`,
+    /// the column is determined by the position of `text` within `line`
+    pub fn new(line_idx: usize, line: &str, text: &str, message: impl fmt::Display) -> Self {
+        Self {
+            file: None,
+            line: line_idx + 1,
+            column: column_of(line, text),
+            text: String::from(text),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates an error for `text` starting at the zero based char index `char_idx` of the line
+    /// with the zero based index `line_idx`
+    pub fn at(
+        line_idx: usize,
+        char_idx: usize,
+        text: impl Into<String>,
+        message: impl fmt::Display,
+    ) -> Self {
+        Self {
+            file: None,
+            line: line_idx + 1,
+            column: char_idx + 1,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn in_file(self, file: impl Into<String>) -> Self {
+        Self {
+            file: Some(file.into()),
+            ..self
+        }
+    }
+}
+
+fn column_of(line: &str, text: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let text_start = text.as_ptr() as usize;
+
+    let offset = if text_start >= line_start && text_start + text.len() <= line_start + line.len() {
+        Some(text_start - line_start)
+    } else {
+        line.find(text)
+    };
+
+    offset.map_or(1, |offset| line[..offset].chars().count() + 1)
+}
+
+/// Iterates over the lines of `reader` together with their zero based index
+pub fn lines(reader: impl BufRead) -> impl Iterator<Item = ParseResult<(usize, String)>> {
+    reader.lines().enumerate().map(|(idx, line)| match line {
+        Ok(line) => Ok((idx, line)),
+        Err(e) => Err(ParseError::new(idx, "", "", e)),
+    })
+}
+
+/// A group of consecutive non-blank lines
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// Zero based index of the first line of the record
+    pub line_idx: usize,
+    /// The lines without line endings and trailing whitespace
+    pub lines: Vec<String>,
+}
+
+impl Record {
+    /// Iterates over the lines together with their zero based index
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(offset, line)| (self.line_idx + offset, line.as_str()))
+    }
+}
+
+/// Splits the lines of a reader into records separated by one or more blank lines
+///
+/// Lines consisting only of whitespace count as blank, LF and CRLF line endings are
+/// supported.
+pub struct RecordReader<R> {
+    lines: io::Lines<R>,
+    line_idx: usize,
+}
+
+impl<R: BufRead> RecordReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_idx: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<R> {
+    type Item = ParseResult<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record: Option<Record> = None;
+
+        for line in &mut self.lines {
+            let idx = self.line_idx;
+            self.line_idx += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(ParseError::new(idx, "", "", e))),
+            };
+            let line = line.trim_end();
+
+            match &mut record {
+                None if line.trim().is_empty() => continue,
+                None => {
+                    record = Some(Record {
+                        line_idx: idx,
+                        lines: vec![String::from(line)],
+                    })
+                }
+                Some(_) if line.trim().is_empty() => break,
+                Some(record) => record.lines.push(String::from(line)),
+            }
+        }
+
+        record.map(Ok)
+    }
+}
+
+/// Parses one value per line, blank lines are skipped
+pub fn values<T>(reader: impl BufRead) -> ParseResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    lines(reader)
+        .filter(|line| !matches!(line, Ok((_, l)) if l.trim().is_empty()))
+        .map(|line| {
+            let (idx, line) = line?;
+            let value = line.trim();
+
+            value
+                .parse()
+                .map_err(|e| ParseError::new(idx, &line, value, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, RecordReader};
+
+    #[test]
+    fn test_error_location() {
+        let line = "  mem[8] = x11";
+        let error = ParseError::new(2, line, &line[11..], "invalid value").in_file("day14.txt");
+
+        assert_eq!(3, error.line);
+        assert_eq!(12, error.column);
+        assert_eq!(
+            "day14.txt:3:12: invalid value, found \"x11\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_record_reader() {
+        let input = "\r\nab:1 c:2  \r\nd:3\r\n\r\n  \r\n\r\ne\n\n\n";
+        let records = RecordReader::new(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(2, records.len());
+        assert_eq!(vec!["ab:1 c:2", "d:3"], records[0].lines);
+        assert_eq!(
+            vec![(1, "ab:1 c:2"), (2, "d:3")],
+            records[0].lines().collect::<Vec<_>>()
+        );
+        assert_eq!(6, records[1].line_idx);
+        assert_eq!(vec!["e"], records[1].lines);
+
+        assert_eq!(0, RecordReader::new("\n \n".as_bytes()).count());
+    }
+}
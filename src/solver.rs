@@ -0,0 +1,221 @@
+use serde::Serialize;
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use thiserror::Error as ThisError;
+
+use crate::bench::{self, Benchmark};
+use crate::{
+    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day3, day4,
+    day5, day6, day7, day8, day9,
+};
+
+pub type Result<T, E = Box<dyn StdError>> = std::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(v) => write!(f, "{}", v),
+            Self::Unsigned(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(v: i32) -> Self {
+        Self::Signed(v.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(v: i64) -> Self {
+        Self::Signed(v)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(v: u32) -> Self {
+        Self::Unsigned(v.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(v: u64) -> Self {
+        Self::Unsigned(v)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(v: usize) -> Self {
+        Self::Unsigned(v as u64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "a" | "A" | "1" => Self::A,
+            "b" | "B" | "2" => Self::B,
+            s => return Err(ParsePartError(String::from(s))),
+        })
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::A => "a",
+                Self::B => "b",
+            }
+        )
+    }
+}
+
+#[derive(ThisError, Debug)]
+#[error("Unknown puzzle part {0}, expected a or b")]
+pub struct ParsePartError(String);
+
+/// A puzzle solution split into a shared parsing step and the two puzzle parts
+pub trait Solution {
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Output1>;
+    fn part2(input: &Self::Input) -> Result<Self::Output2>;
+
+    /// Auxiliary results of part 1 worth reporting next to the answer
+    fn details1(_input: &Self::Input) -> Result<Details> {
+        Ok(Details::new())
+    }
+
+    /// Auxiliary results of part 2 worth reporting next to the answer
+    fn details2(_input: &Self::Input) -> Result<Details> {
+        Ok(Details::new())
+    }
+}
+
+pub type Details = serde_json::Map<String, serde_json::Value>;
+
+/// The answer of a solver run together with its auxiliary results and timings
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: Answer,
+    pub details: Details,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub struct Solver {
+    pub day: u8,
+    pub part: Part,
+    run: fn(&str) -> Result<Solved>,
+}
+
+impl Solver {
+    pub fn run(&self, input: &str) -> Result<Solved> {
+        (self.run)(input)
+    }
+
+    pub fn solve(&self, input: &str) -> Result<Answer> {
+        self.run(input).map(|solved| solved.answer)
+    }
+}
+
+fn run_part<S: Solution, T: Into<Answer>>(
+    input: &str,
+    solve: fn(&S::Input) -> Result<T>,
+    details: fn(&S::Input) -> Result<Details>,
+) -> Result<Solved> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve(&input)?.into();
+    let solve_time = start.elapsed();
+
+    Ok(Solved {
+        answer,
+        details: details(&input)?,
+        parse_time,
+        solve_time,
+    })
+}
+
+fn run_part1<S: Solution>(input: &str) -> Result<Solved> {
+    run_part::<S, _>(input, S::part1, S::details1)
+}
+
+fn run_part2<S: Solution>(input: &str) -> Result<Solved> {
+    run_part::<S, _>(input, S::part2, S::details2)
+}
+
+macro_rules! solutions {
+    ($($day:literal => $solution:ty),* $(,)?) => {
+        pub static SOLVERS: &[Solver] = &[
+            $(
+                Solver {
+                    day: $day,
+                    part: Part::A,
+                    run: run_part1::<$solution>,
+                },
+                Solver {
+                    day: $day,
+                    part: Part::B,
+                    run: run_part2::<$solution>,
+                },
+            )*
+        ];
+
+        pub static BENCHMARKS: &[Benchmark] = &[
+            $(Benchmark::new($day, bench::time_phases::<$solution>),)*
+        ];
+    };
+}
+
+solutions! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+}
+
+pub fn find_solver(day: u8, part: Part) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}